@@ -0,0 +1,55 @@
+//! Scripted collisions between two catalogued creatures.
+//!
+//! Picks two entries from the creature library (data/library, filled by the
+//! search example), measures each one's heading by running it alone, and
+//! sweeps approach angles × impact parameters, printing the outcome table. A
+//! creature that does not translate is used as a stationary target.
+//!
+//! The world runs the first creature's rule; the second keeps its own only
+//! through its localized genome, if it carries one.
+//!
+//! Usage:
+//!   cargo run --release --example collide <id-a> [id-b] [steps]
+
+use seeker::behavior::{classify, ClassifyConfig};
+use seeker::collision::{sweep, table, Collider, CollisionConfig};
+use seeker::library::Library;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(id_a) = args.get(1) else {
+        eprintln!("usage: collide <id-a> [id-b] [steps]");
+        std::process::exit(2);
+    };
+    let id_b = args.get(2).unwrap_or(id_a);
+    let steps = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(600);
+
+    let (library, skipped) = Library::open("data/library").expect("open data/library");
+    for s in &skipped {
+        eprintln!("skipped {}: {}", s.path.display(), s.error);
+    }
+    let classify_cfg = ClassifyConfig::default();
+    let collider = |id: &str| {
+        let entry = library.get(id).unwrap_or_else(|| panic!("no library entry {id}"));
+        let creature = entry.creature.clone();
+        let alone = classify(&creature, &classify_cfg);
+        println!(
+            "{id}: {} (confidence {:.2}), mass {:.1}",
+            alone.behavior.name(),
+            alone.confidence,
+            creature.mass()
+        );
+        let (vx, vy) = alone.velocity;
+        Collider::new(creature, vy.atan2(vx))
+    };
+    let a = collider(id_a);
+    let b = collider(id_b);
+
+    let cfg = CollisionConfig { steps, ..Default::default() };
+    println!(
+        "\n{} angles × {} offsets, {steps} steps each\n",
+        cfg.angles.len(),
+        cfg.offsets.len()
+    );
+    print!("{}", table(&sweep(&a, &b, &cfg)));
+}
@@ -9,7 +9,8 @@
 
 use rand::SeedableRng;
 use seeker::flow_lenia::{FlowLeniaParams, World};
-use seeker::harness::{connected_components, field_stats, measure_run, Tracker};
+use seeker::harness::{activity, connected_components, field_stats, RunMeter, Tracker};
+use seeker::observer::drive;
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
@@ -35,23 +36,17 @@ fn main() {
     println!("\n  step | conc  | blobs | mean-sz | activity |  speed | maxspd | occ");
     println!("  -----|-------|-------|---------|----------|--------|--------|------");
 
-    // Manual loop mirroring measure_run so we can print a live time series.
+    // A printing observer for the live time series, driven alongside the
+    // meter that folds the same run into the summary.
     let mut tracker = Tracker::new(w, h, 8.0);
     let mut prev: Option<Vec<f32>> = None;
     let report_every = (steps / 15).max(1);
-    for step in 0..=steps {
-        if step > 0 {
-            world.step();
-        }
-        let show = step % report_every == 0 || step == steps;
-        if !show {
-            continue;
-        }
+    let mut print_row = |world: &World, step: usize| {
         let field = world.mass_field();
         let stats = field_stats(&field, threshold);
         let comps = connected_components(&field, w, h, threshold);
         let vel = tracker.observe(&comps);
-        let act = prev.as_ref().map(|p| seeker::harness::activity(p, &field)).unwrap_or(0.0);
+        let act = prev.as_ref().map(|p| activity(p, &field)).unwrap_or(0.0);
         prev = Some(field);
         println!(
             "  {:4} | {:.3} | {:5} | {:7.1} | {:8.5} | {:6.3} | {:6.3} | {:.3}",
@@ -64,22 +59,10 @@ fn main() {
             vel.max_speed,
             stats.occupied_fraction,
         );
-    }
-
-    // Reset and fold the same run into a summary fingerprint.
-    let mut world2 = World::new(w, h, FlowLeniaParams::default());
-    match seed_mode {
-        "soup" => {
-            let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
-            world2.seed_random_patch(&mut rng, 0, 64.0, 64.0, 90.0, 0.6);
-        }
-        _ => {
-            world2.seed_blob(0, 64.0, 64.0, 9.0, 0.95);
-            world2.seed_blob(0, 40.0, 80.0, 5.0, 0.8);
-            world2.seed_blob(0, 90.0, 40.0, 4.0, 0.7);
-        }
-    }
-    let (summary, _series) = measure_run(&mut world2, steps, report_every, threshold, 8.0);
+    };
+    let mut meter = RunMeter::new(&world, steps, threshold, 8.0);
+    drive(&mut world, steps, report_every, &mut [&mut print_row, &mut meter]);
+    let (summary, _series) = meter.finish(&world);
 
     println!("\n=== run summary (behavior fingerprint) ===");
     println!("  mass drift         : {:.2e}  (conservation check)", summary.mass_drift);
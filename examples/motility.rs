@@ -16,7 +16,9 @@
 //!   cargo run --release --example motility [generations] [out.gif]
 
 use seeker::flow_lenia::World;
-use seeker::search::{map_elites, EvalConfig, Genome, MapConfig, Objective, SearchConfig};
+use seeker::search::{
+    map_elites, EvalConfig, Genome, MapConfig, Objective, SearchConfig, N_SHAPE_GENES,
+};
 use std::borrow::Cow;
 use std::fs::File;
 
@@ -143,6 +145,8 @@ fn default_rule_genome() -> Genome {
         // Energy and detritus genes are inert for the (non-metabolic) motility path.
         energy: [0.5, 0.15, 0.004, 0.15],
         detritus: [0.05, 0.01, 0.5],
+        // Radial kernel.
+        shape: [0.0; N_SHAPE_GENES],
     }
 }
 
@@ -6,12 +6,15 @@
 //!
 //! After the search, the highest-quality discovered rule is re-run and exported
 //! as data/evolved-best.gif — illuminate the space, then watch the winner.
+//! Every live elite is also catalogued in the creature library under
+//! data/library, so finds accumulate across runs.
 //!
 //! Usage:
 //!   cargo run --release --example search [generations] [init_batch] [batch]
 
 use rand::{rngs::StdRng, SeedableRng};
 use seeker::flow_lenia::World;
+use seeker::library::{Added, Entry, Library};
 use seeker::search::{map_elites, EvalConfig, Evaluated, MapElites, SearchConfig};
 use std::borrow::Cow;
 use std::fs::File;
@@ -79,6 +82,23 @@ fn main() {
         print_elite("most motile (alive)", e);
     }
 
+    // Keep the finds: catalogue every live elite in the persistent library.
+    let (mut library, skipped) = Library::open("data/library").expect("open library");
+    for s in &skipped {
+        eprintln!("skipped {}: {}", s.path.display(), s.error);
+    }
+    let (mut kept, mut dups) = (0, 0);
+    for entry in alive.iter().filter_map(|e| Entry::from_elite(e, &cfg.eval)) {
+        match library.add(entry).expect("write library entry") {
+            Added::New(_) | Added::Replaced(_) => kept += 1,
+            Added::Duplicate(_) => dups += 1,
+        }
+    }
+    println!(
+        "\nLibrary data/library: {kept} new or improved, {dups} duplicates, {} creatures",
+        library.len()
+    );
+
     // Watch the winner: re-run the best rule and export a GIF.
     if let Some(best) = archive.best() {
         let out = "data/evolved-best.gif";
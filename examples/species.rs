@@ -16,6 +16,7 @@
 
 use rand::SeedableRng;
 use seeker::flow_lenia::{FlowLeniaParams, World};
+use seeker::harness::{species_census, SpeciesConfig};
 use std::borrow::Cow;
 use std::fs::File;
 
@@ -71,8 +72,8 @@ fn main() {
         println!("  genome territory: {label}  (μ={mu})");
     }
     let (m0, v0) = world.mu_stats().unwrap();
-    println!("\n  step | mass drift | occupied | μ mean  | μ var     | μ range        | blend%");
-    println!("  -----|------------|----------|---------|-----------|----------------|-------");
+    println!("\n  step | mass drift | occupied | μ mean  | μ var     | μ range        | blend% | species | simpson");
+    println!("  -----|------------|----------|---------|-----------|----------------|--------|---------|--------");
 
     let palette = build_palette();
     let gw = w as u16 * CELL;
@@ -115,8 +116,18 @@ fn report(step: usize, world: &World, initial_mass: f64, mean: f32, var: f32) {
     let occ = world.occupied_fraction(0.05);
     let (lo, hi) = occupied_mu_range(world, 0.05);
     let blend = blend_mass_fraction(world, 0.05, 0.006) * 100.0;
+    let census = species_census(
+        world.channel(0),
+        world.mu_field().expect("genome enabled"),
+        world.sigma_field().expect("genome enabled"),
+        world.width(),
+        world.height(),
+        0.05,
+        &SpeciesConfig::default(),
+    );
+    let (richness, simpson) = (census.richness(), census.simpson());
     println!(
-        "  {step:4} | {drift:10.2e} | {occ:8.3} | {mean:7.4} | {var:9.2e} | [{lo:.3}, {hi:.3}] | {blend:5.1}"
+        "  {step:4} | {drift:10.2e} | {occ:8.3} | {mean:7.4} | {var:9.2e} | [{lo:.3}, {hi:.3}] | {blend:6.1} | {richness:7} | {simpson:7.3}"
     );
 }
 
@@ -0,0 +1,487 @@
+//! What a creature does on its own: the Flow-Lenia counterpart of
+//! [`classify_component`](crate::analysis::classify_component).
+//!
+//! A discrete pattern is classified by running it in isolation until it
+//! repeats exactly. Continuous matter never repeats exactly, so here a
+//! [`Creature`] is stamped alone into an empty world of its own rule, left to
+//! settle, and then watched for a while. Every step records the mass centroid,
+//! the principal axis of the mass, the blob count and a centroid-aligned crop
+//! of the mass field. From those the classifier reads:
+//!
+//! - **dissipating** — the above-threshold mass fades away;
+//! - **dividing** — the creature persistently becomes several blobs;
+//! - **translating** — the centroid travels, with its velocity;
+//! - **rotating** — the principal axis turns steadily, with its rate;
+//! - **oscillating** — the shape changes but returns, with the period at which
+//!   the crop correlates best with itself;
+//! - **stationary** — none of the above and the shape holds still.
+//!
+//! Anything that changes shape without returning is **irregular**, the analogue
+//! of an unclassified discrete pattern. Each verdict carries a confidence in
+//! `[0, 1]`, and the raw measurements stay available on the
+//! [`Classification`] so callers can apply their own cuts. [`census`] runs the
+//! classifier over every blob in a world.
+
+use crate::creature::Creature;
+use crate::flow_lenia::World;
+use crate::harness::{connected_components, Components};
+
+/// Knobs of the isolation experiment.
+#[derive(Clone, Debug)]
+pub struct ClassifyConfig {
+    /// Steps run in total, settling included.
+    pub steps: usize,
+    /// Steps left to settle before anything is recorded: stamping perturbs a
+    /// creature briefly, and the transient is not its behavior.
+    pub settle: usize,
+    /// Blob threshold on the mass field.
+    pub threshold: f32,
+    /// Longest oscillation period looked for, in steps.
+    pub max_period: usize,
+    /// Net travel, in cells over the recorded window, below which a creature
+    /// does not count as translating.
+    pub min_travel: f32,
+    /// Net turn of the principal axis, in radians over the recorded window,
+    /// below which a creature does not count as rotating.
+    pub min_turn: f32,
+    /// Shape variation (one minus the worst lagged crop correlation) below
+    /// which the shape counts as still.
+    pub shape_tolerance: f32,
+}
+
+impl Default for ClassifyConfig {
+    fn default() -> Self {
+        Self {
+            steps: 400,
+            settle: 50,
+            threshold: 0.05,
+            max_period: 60,
+            min_travel: 3.0,
+            min_turn: std::f32::consts::FRAC_PI_4,
+            shape_tolerance: 0.02,
+        }
+    }
+}
+
+/// The behavior a creature shows in isolation.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Behavior {
+    /// Holds its shape in place.
+    Stationary,
+    /// Changes shape in place and returns to it every `period` steps.
+    Oscillating { period: usize },
+    /// Travels with velocity `(vx, vy)`, in cells per step.
+    Translating { vx: f32, vy: f32 },
+    /// Turns in place at `rate` radians per step (positive from `+x` toward
+    /// `+y`).
+    Rotating { rate: f32 },
+    /// Persistently splits into `blobs` separate blobs.
+    Dividing { blobs: usize },
+    /// Fades below the blob threshold.
+    Dissipating,
+    /// Changes shape without returning to it.
+    Irregular,
+}
+
+impl Behavior {
+    /// Short label for reports and censuses.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Behavior::Stationary => "stationary",
+            Behavior::Oscillating { .. } => "oscillating",
+            Behavior::Translating { .. } => "translating",
+            Behavior::Rotating { .. } => "rotating",
+            Behavior::Dividing { .. } => "dividing",
+            Behavior::Dissipating => "dissipating",
+            Behavior::Irregular => "irregular",
+        }
+    }
+}
+
+/// A verdict and the measurements behind it.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Classification {
+    pub behavior: Behavior,
+    /// How clearly the measurements support `behavior`, in `[0, 1]`.
+    pub confidence: f32,
+    /// Mean centroid velocity over the recorded window, in cells per step.
+    pub velocity: (f32, f32),
+    /// Net travel over path length, in `[0, 1]`: 1 = a straight line.
+    pub straightness: f32,
+    /// Mean turn of the principal axis, in radians per step.
+    pub rotation_rate: f32,
+    /// Mean principal-axis elongation (major over minor radius, ≥ 1). Near 1
+    /// the axis is ill-defined and rotation cannot be seen.
+    pub elongation: f32,
+    /// Best oscillation period found, if the shape varies beyond
+    /// [`shape_tolerance`](ClassifyConfig::shape_tolerance).
+    pub period: Option<usize>,
+    /// How fully the shape returns at `period`, in `[0, 1]`.
+    pub period_strength: f32,
+    /// One minus the worst mean crop correlation over lags `1..=max_period`.
+    pub shape_variation: f32,
+    /// Above-threshold mass at the end over that at the start of recording.
+    pub mass_retained: f32,
+    /// Blob count at the end of the run.
+    pub blobs: usize,
+}
+
+/// Run `creature` alone in an empty world of its own rule and classify what it
+/// does.
+///
+/// # Panics
+/// If `cfg.steps` leaves fewer than two recorded steps after `cfg.settle`.
+pub fn classify(creature: &Creature, cfg: &ClassifyConfig) -> Classification {
+    assert!(cfg.steps > cfg.settle + 1, "nothing left to record after settling");
+    let extent = creature.width.max(creature.height);
+    // Room for the creature and a kernel's reach either side, so it never
+    // feels itself round the torus.
+    let side = (extent + 4 * creature.params.kernel_radius).max(64);
+    let mut world = World::new(side, side, creature.params.clone());
+    let mid = side as f32 * 0.5;
+    creature.stamp(&mut world, mid, mid, 0.0);
+    for _ in 0..cfg.settle {
+        world.step();
+    }
+
+    // Crop half-size: the creature plus its halo, with a little slack.
+    let half = extent / 2 + 4;
+    let mut frames = Vec::new();
+    let mut moments = Vec::new();
+    let mut path = Vec::new();
+    let mut counts = Vec::new();
+    let mut first_mass = None;
+    let mut last_mass = 0.0f32;
+    for step in cfg.settle..cfg.steps {
+        if step > cfg.settle {
+            world.step();
+        }
+        let field = world.mass_field();
+        let comps = connected_components(&field, side, side, cfg.threshold);
+        last_mass = blob_mass(&comps);
+        first_mass.get_or_insert(last_mass);
+        counts.push(comps.count());
+        let Some(center) = world.center_of_mass() else {
+            break;
+        };
+        path.push(center);
+        let (crop, axis) = crop_around(&field, side, center, half);
+        frames.push(crop);
+        moments.push(axis);
+    }
+
+    let mass_retained = match first_mass {
+        Some(m) if m > 0.0 => last_mass / m,
+        _ => 0.0,
+    };
+    let blobs = counts.last().copied().unwrap_or(0);
+    let recorded = frames.len();
+
+    // Centroid motion, unwrapped step by step round the torus.
+    let (mut net, mut length) = ((0.0f32, 0.0f32), 0.0f32);
+    for pair in path.windows(2) {
+        let dx = torus_delta(pair[1].0, pair[0].0, side as f32);
+        let dy = torus_delta(pair[1].1, pair[0].1, side as f32);
+        net = (net.0 + dx, net.1 + dy);
+        length += (dx * dx + dy * dy).sqrt();
+    }
+    let travel = (net.0 * net.0 + net.1 * net.1).sqrt();
+    let span = recorded.saturating_sub(1).max(1) as f32;
+    let velocity = (net.0 / span, net.1 / span);
+    let straightness = if length > 0.0 { travel / length } else { 0.0 };
+
+    // Principal-axis turning; the axis is only defined modulo π.
+    let (mut turn, mut turned) = (0.0f32, 0.0f32);
+    for pair in moments.windows(2) {
+        let d = (pair[1].0 - pair[0].0 + std::f32::consts::FRAC_PI_2)
+            .rem_euclid(std::f32::consts::PI)
+            - std::f32::consts::FRAC_PI_2;
+        turn += d;
+        turned += d.abs();
+    }
+    let rotation_rate = turn / span;
+    let elongation = if moments.is_empty() {
+        1.0
+    } else {
+        moments.iter().map(|m| m.1).sum::<f32>() / moments.len() as f32
+    };
+
+    let (shape_variation, period, period_strength) =
+        shape_cycle(&frames, cfg.max_period, cfg.shape_tolerance);
+
+    // The last quarter decides division: a transient split that re-merges is
+    // not one.
+    let mut tail = counts[counts.len() - (counts.len() / 4).max(1)..].to_vec();
+    let split = tail.iter().filter(|&&c| c >= 2).count() as f32 / tail.len() as f32;
+    tail.sort_unstable();
+    let settled_blobs = tail[tail.len() / 2];
+
+    let (behavior, confidence) = if blobs == 0 || mass_retained < 0.5 {
+        (Behavior::Dissipating, (1.0 - mass_retained).clamp(0.0, 1.0))
+    } else if split >= 0.75 {
+        (Behavior::Dividing { blobs: settled_blobs }, split)
+    } else if travel >= cfg.min_travel {
+        (Behavior::Translating { vx: velocity.0, vy: velocity.1 }, straightness)
+    } else if elongation >= 1.15 && turn.abs() >= cfg.min_turn {
+        (Behavior::Rotating { rate: rotation_rate }, turn.abs() / turned)
+    } else if let Some(period) = period {
+        (Behavior::Oscillating { period }, period_strength)
+    } else if shape_variation <= cfg.shape_tolerance {
+        (Behavior::Stationary, 1.0 - shape_variation / cfg.shape_tolerance)
+    } else {
+        (Behavior::Irregular, shape_variation.min(1.0))
+    };
+
+    Classification {
+        behavior,
+        confidence,
+        velocity,
+        straightness,
+        rotation_rate,
+        elongation,
+        period,
+        period_strength,
+        shape_variation,
+        mass_retained,
+        blobs,
+    }
+}
+
+/// Classify every blob of `world`, largest first, each cut out at
+/// `cfg.threshold` and run alone.
+pub fn census(world: &World, cfg: &ClassifyConfig) -> Vec<Classification> {
+    let count =
+        connected_components(&world.mass_field(), world.width(), world.height(), cfg.threshold)
+            .count();
+    (0..count)
+        .filter_map(|blob| Creature::extract(world, blob, cfg.threshold))
+        .map(|creature| classify(&creature, cfg))
+        .collect()
+}
+
+/// Shape self-similarity of a run of equal-size crops: the shape variation
+/// (one minus the worst mean correlation over lags `1..=max_period`), and —
+/// if that exceeds `tolerance` — the oscillation period with its strength.
+/// The period is the first local peak of correlation against lag, past lag
+/// 1, that recovers at least three quarters of the dip; its strength is the
+/// fraction of the dip it recovers.
+fn shape_cycle(
+    frames: &[Vec<f32>],
+    max_period: usize,
+    tolerance: f32,
+) -> (f32, Option<usize>, f32) {
+    let max_lag = max_period.min(frames.len() / 2);
+    let lagged: Vec<f32> = (1..=max_lag)
+        .map(|lag| {
+            let pairs = frames.len() - lag;
+            (0..pairs).map(|t| correlation(&frames[t], &frames[t + lag])).sum::<f32>()
+                / pairs as f32
+        })
+        .collect();
+    let worst = lagged.iter().copied().fold(1.0f32, f32::min);
+    let variation = (1.0 - worst).max(0.0);
+    if variation <= tolerance {
+        return (variation, None, 0.0);
+    }
+    // `lagged[i]` is the correlation at lag `i + 1`.
+    let peak = (1..lagged.len()).find(|&i| {
+        let next = lagged.get(i + 1).copied().unwrap_or(f32::MIN);
+        lagged[i] >= lagged[i - 1] && lagged[i] >= next && lagged[i] >= 1.0 - 0.25 * variation
+    });
+    match peak {
+        Some(i) => (variation, Some(i + 1), ((lagged[i] - worst) / variation).clamp(0.0, 1.0)),
+        None => (variation, None, 0.0),
+    }
+}
+
+fn blob_mass(comps: &Components) -> f32 {
+    comps.blobs.iter().map(|b| b.mass).sum()
+}
+
+/// Signed shortest displacement `a − b` on a ring of length `n`.
+fn torus_delta(a: f32, b: f32, n: f32) -> f32 {
+    (a - b + n * 0.5).rem_euclid(n) - n * 0.5
+}
+
+/// The `(2·half + 1)²` crop of `field` centered on the cell nearest `center`,
+/// wrapped round the torus, and the crop's principal axis as `(angle,
+/// elongation)`.
+fn crop_around(
+    field: &[f32],
+    side: usize,
+    center: (f32, f32),
+    half: usize,
+) -> (Vec<f32>, (f32, f32)) {
+    let (cx, cy) = (center.0.round() as i64, center.1.round() as i64);
+    let (fx, fy) = (center.0 - cx as f32, center.1 - cy as f32);
+    let n = side as i64;
+    let h = half as i64;
+    let mut crop = Vec::with_capacity(((2 * h + 1) * (2 * h + 1)) as usize);
+    let (mut sxx, mut syy, mut sxy, mut m) = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+    for dy in -h..=h {
+        for dx in -h..=h {
+            let x = (cx + dx).rem_euclid(n) as usize;
+            let y = (cy + dy).rem_euclid(n) as usize;
+            let v = field[y * side + x];
+            crop.push(v);
+            let (rx, ry) = ((dx as f32 - fx) as f64, (dy as f32 - fy) as f64);
+            let v = v as f64;
+            sxx += v * rx * rx;
+            syy += v * ry * ry;
+            sxy += v * rx * ry;
+            m += v;
+        }
+    }
+    if m <= 0.0 {
+        return (crop, (0.0, 1.0));
+    }
+    let (sxx, syy, sxy) = (sxx / m, syy / m, sxy / m);
+    let angle = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    let mean = 0.5 * (sxx + syy);
+    let spread = (0.25 * (sxx - syy) * (sxx - syy) + sxy * sxy).sqrt();
+    let (major, minor) = (mean + spread, (mean - spread).max(1e-12));
+    (crop, (angle as f32, (major / minor).sqrt() as f32))
+}
+
+/// Pearson correlation of two equal-length fields; 1 if either is flat.
+fn correlation(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len() as f64;
+    let ma = a.iter().map(|&v| v as f64).sum::<f64>() / n;
+    let mb = b.iter().map(|&v| v as f64).sum::<f64>() / n;
+    let (mut ab, mut aa, mut bb) = (0.0f64, 0.0f64, 0.0f64);
+    for (&x, &y) in a.iter().zip(b) {
+        let (x, y) = (x as f64 - ma, y as f64 - mb);
+        ab += x * y;
+        aa += x * x;
+        bb += y * y;
+    }
+    if aa <= 0.0 || bb <= 0.0 {
+        return 1.0;
+    }
+    (ab / (aa * bb).sqrt()) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_lenia::{FlowLeniaParams, KernelShape};
+
+    /// Short runs on a small kernel keep these tests fast.
+    fn quick() -> ClassifyConfig {
+        ClassifyConfig { steps: 200, settle: 20, max_period: 30, ..Default::default() }
+    }
+
+    fn small_rule() -> FlowLeniaParams {
+        FlowLeniaParams { kernel_radius: 7, ..FlowLeniaParams::default() }
+    }
+
+    /// Growth negative everywhere but rising with potential: matter just
+    /// flows up the potential gradient. With an offset kernel the potential
+    /// peak sits off the blob, which chases it.
+    fn chase_rule(shape: KernelShape) -> FlowLeniaParams {
+        FlowLeniaParams { shape, dt: 0.5, growth_mu: 0.45, growth_sigma: 0.2, ..small_rule() }
+    }
+
+    /// A creature cut from a single Gaussian blob.
+    fn lone(params: FlowLeniaParams, radius: f32, amp: f32) -> Creature {
+        let mut world = World::new(48, 48, params);
+        world.seed_blob(0, 24.0, 24.0, radius, amp);
+        Creature::extract(&world, 0, 0.05).unwrap()
+    }
+
+    #[test]
+    fn classifies_creatures_that_stay_put() {
+        // Growth is negative everywhere and flat: nothing moves.
+        let frozen = FlowLeniaParams { growth_mu: 0.45, ..small_rule() };
+        let still = classify(&lone(frozen, 3.0, 0.9), &quick());
+        assert_eq!(still.behavior, Behavior::Stationary, "{still:?}");
+        assert!(still.confidence > 0.5 && still.period.is_none());
+
+        // The default rule breaks a big blob into separate spots.
+        let split = classify(&lone(small_rule(), 3.0, 0.9), &quick());
+        assert!(matches!(split.behavior, Behavior::Dividing { blobs } if blobs >= 2), "{split:?}");
+
+        // Pure mass repulsion spreads a faint blob below the threshold.
+        let diffuse = FlowLeniaParams {
+            dt: 0.5,
+            growth_mu: 0.45,
+            theta_a: 0.05,
+            alpha_n: 1.0,
+            ..small_rule()
+        };
+        let faded = classify(&lone(diffuse, 3.0, 0.1), &quick());
+        assert_eq!(faded.behavior, Behavior::Dissipating, "{faded:?}");
+        assert!(faded.mass_retained < 0.5);
+    }
+
+    #[test]
+    fn classifies_translation_and_rotation() {
+        let offset = KernelShape { offset: 0.5, ..KernelShape::default() };
+        let moving = classify(&lone(chase_rule(offset), 3.0, 0.9), &quick());
+        let Behavior::Translating { vx, vy } = moving.behavior else {
+            panic!("expected a translating creature, got {moving:?}");
+        };
+        // Along the kernel's orientation axis (x), in a straight line.
+        assert!(vx.abs() > 0.01 && vy.abs() < 1e-3, "velocity ({vx}, {vy})");
+        assert!(moving.confidence > 0.9);
+
+        // A dumbbell whose halves push tangentially turns like a propeller,
+        // the other way round when they push the other way.
+        let spin = |sense: f32| {
+            let directed = KernelShape { harmonic: 1, amplitude: 1.0, ..KernelShape::default() };
+            let mut world = World::new(48, 48, chase_rule(directed));
+            world.enable_genome();
+            world.seed_blob(0, 21.0, 24.0, 2.5, 0.9);
+            world.seed_blob(0, 27.0, 24.0, 2.5, 0.9);
+            for y in 0..48 {
+                for x in 0..48 {
+                    let mut genes = world.genes(x, y).unwrap();
+                    let around = (y as f32 - 24.0).atan2(x as f32 - 24.0);
+                    genes.orientation = around + sense * std::f32::consts::FRAC_PI_2;
+                    world.set_genes(x, y, genes);
+                }
+            }
+            classify(&Creature::extract(&world, 0, 0.05).unwrap(), &quick())
+        };
+        let (left, right) = (spin(1.0), spin(-1.0));
+        let (Behavior::Rotating { rate: a }, Behavior::Rotating { rate: b }) =
+            (left.behavior, right.behavior)
+        else {
+            panic!("expected rotating creatures, got {left:?} and {right:?}");
+        };
+        assert!(a.abs() > 2e-3 && a.signum() != b.signum(), "rates {a} and {b}");
+    }
+
+    #[test]
+    fn shape_cycle_finds_the_period() {
+        // A Gaussian breathing with period 12.
+        let frame = |t: usize| {
+            let sigma = 3.0 + (std::f32::consts::TAU * t as f32 / 12.0).sin();
+            (0..21 * 21)
+                .map(|i| {
+                    let (x, y) = ((i % 21) as f32 - 10.0, (i / 21) as f32 - 10.0);
+                    (-(x * x + y * y) / (2.0 * sigma * sigma)).exp()
+                })
+                .collect::<Vec<f32>>()
+        };
+        let breathing: Vec<_> = (0..100).map(frame).collect();
+        let (variation, period, strength) = shape_cycle(&breathing, 30, 0.02);
+        assert!(variation > 0.02);
+        assert_eq!(period, Some(12));
+        assert!(strength > 0.9, "strength {strength}");
+
+        let still = vec![frame(0); 100];
+        assert_eq!(shape_cycle(&still, 30, 0.02), (0.0, None, 0.0));
+    }
+
+    #[test]
+    fn census_classifies_every_blob() {
+        let mut world =
+            World::new(64, 48, chase_rule(KernelShape { offset: 0.5, ..KernelShape::default() }));
+        world.seed_blob(0, 16.0, 24.0, 3.0, 0.9);
+        world.seed_blob(0, 48.0, 24.0, 3.0, 0.9);
+        let census = census(&world, &quick());
+        assert_eq!(census.len(), 2);
+        assert!(census.iter().all(|c| c.behavior.name() == "translating"), "{census:?}");
+    }
+}
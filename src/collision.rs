@@ -0,0 +1,373 @@
+//! Scripted collisions between creatures.
+//!
+//! Motile species matter because they migrate and collide, but in an open
+//! world collisions happen only by chance. Here they happen on purpose: two
+//! [`Collider`]s — a creature and the heading it travels along — are stamped
+//! into an empty world on a collision course, with `b` approaching `a` at a
+//! chosen angle and impact parameter, and the world is run for a fixed time.
+//! [`sweep`] repeats that over a grid of angles and offsets, and [`table`]
+//! lays the outcomes out for reading.
+//!
+//! Each run ends in an [`Outcome`]: the pair missed, passed through each
+//! other, merged into one blob, split into more than two, or dispersed below
+//! the blob threshold. A pair that is not two separate blobs once stamped —
+//! too close, or too big for the separation — is reported as such and not
+//! run. When the two creatures carry different localized genomes (M-γ-1), the
+//! run also reports **genome dominance**: the share of the surviving matter
+//! whose `μ` is nearer `a`'s than `b`'s. The world runs `a`'s rule, so species
+//! differences have to live in the genome.
+
+use crate::behavior::{classify, Behavior, ClassifyConfig};
+use crate::creature::Creature;
+use crate::flow_lenia::World;
+use crate::harness::{connected_components, Blob};
+use std::f32::consts::PI;
+use std::fmt::Write as _;
+
+/// A creature and the direction it travels in, in radians from `+x` toward
+/// `+y`, as it moves when stamped unrotated.
+#[derive(Clone, Debug)]
+pub struct Collider {
+    pub creature: Creature,
+    pub heading: f32,
+}
+
+impl Collider {
+    pub fn new(creature: Creature, heading: f32) -> Self {
+        Self { creature, heading }
+    }
+
+    /// Measure the heading by running the creature alone (see
+    /// [`classify`]). `None` if it does not translate.
+    pub fn measured(creature: Creature, cfg: &ClassifyConfig) -> Option<Self> {
+        match classify(&creature, cfg).behavior {
+            Behavior::Translating { vx, vy } => Some(Self::new(creature, vy.atan2(vx))),
+            _ => None,
+        }
+    }
+}
+
+/// The grid of collisions a [`sweep`] runs, and how long each runs.
+#[derive(Clone, Debug)]
+pub struct CollisionConfig {
+    /// Approach angles of `b`, in radians: `0` is head-on, `π/2` hits `a`
+    /// from the side. Keep clear of `±π`, where `b` would start on top of
+    /// `a`.
+    pub angles: Vec<f32>,
+    /// Impact parameters: how far `b`'s line of travel passes beside `a`'s
+    /// starting line through the collision point, in cells.
+    pub offsets: Vec<f32>,
+    /// Distance between the two mass centroids at the start, along the
+    /// approach. `None` = the creatures' half extents plus a kernel radius,
+    /// so they start just out of each other's reach.
+    pub separation: Option<f32>,
+    /// Steps per collision.
+    pub steps: usize,
+    /// Blob threshold on the mass field.
+    pub threshold: f32,
+    /// Blobs holding less than this fraction of the starting above-threshold
+    /// mass are debris — shed wisps, not creatures — and are not counted.
+    pub debris: f32,
+}
+
+impl Default for CollisionConfig {
+    fn default() -> Self {
+        Self {
+            angles: vec![0.0, PI / 4.0, PI / 2.0, 3.0 * PI / 4.0],
+            offsets: vec![0.0, 2.0, 4.0, 8.0],
+            separation: None,
+            steps: 600,
+            threshold: 0.05,
+            debris: 0.05,
+        }
+    }
+}
+
+/// How a collision ended.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Outcome {
+    /// The creatures never touched.
+    Missed,
+    /// They touched and came apart again as two.
+    PassedThrough,
+    /// They fused into one blob.
+    Merged,
+    /// They ended as `blobs` (three or more) separate blobs.
+    Split { blobs: usize },
+    /// Most of the matter fell below the blob threshold.
+    Dispersed,
+    /// Once stamped, the pair was `blobs` blobs rather than two separate
+    /// ones (their footprints already touched, or a creature was in pieces),
+    /// so contact could not be told; the run was skipped.
+    Unseparated { blobs: usize },
+}
+
+impl Outcome {
+    /// Short label for tables.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Missed => "missed",
+            Outcome::PassedThrough => "pass-through",
+            Outcome::Merged => "merged",
+            Outcome::Split { .. } => "split",
+            Outcome::Dispersed => "dispersed",
+            Outcome::Unseparated { .. } => "unseparated",
+        }
+    }
+}
+
+/// One collision and what came of it.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Collision {
+    /// Approach angle and impact parameter the run used.
+    pub angle: f32,
+    pub offset: f32,
+    pub outcome: Outcome,
+    /// Blob count at the end, debris aside.
+    pub blobs: usize,
+    /// Above-threshold mass at the end over that at the start.
+    pub mass_retained: f32,
+    /// Share of the surviving above-threshold mass whose `μ` is nearer `a`'s
+    /// than `b`'s, if the world carries a genome and the two differ in `μ`.
+    pub dominance: Option<f32>,
+}
+
+/// Collide `b` into `a` at approach `angle` with impact parameter `offset`
+/// (see [`CollisionConfig`]), run for `cfg.steps`, and classify the outcome.
+///
+/// # Panics
+/// If the two creatures have different channel counts.
+pub fn collide(
+    a: &Collider,
+    b: &Collider,
+    angle: f32,
+    offset: f32,
+    cfg: &CollisionConfig,
+) -> Collision {
+    let params = a.creature.params.clone();
+    let extent = |c: &Creature| c.width.max(c.height) as f32;
+    let (ea, eb) = (extent(&a.creature), extent(&b.creature));
+    let separation =
+        cfg.separation.unwrap_or(0.5 * (ea + eb) + params.kernel_radius as f32);
+    // Room for both starting points and a kernel's reach beyond each, so the
+    // pair never meets round the back of the torus.
+    let reach = 2.0 * params.kernel_radius as f32;
+    let side = (2.0 * (0.5 * separation + offset.abs()) + ea + eb + reach).ceil() as usize;
+    let side = side.max(64);
+    let mut world = World::new(side, side, params);
+
+    // `a` heads along +x into the collision point at the center; `b` comes
+    // at it from direction `angle`, displaced sideways by `offset`.
+    let mid = side as f32 * 0.5;
+    let (sin, cos) = angle.sin_cos();
+    a.creature.stamp(&mut world, mid - 0.5 * separation, mid, -a.heading);
+    let (bx, by) =
+        (mid + 0.5 * separation * cos - offset * sin, mid + 0.5 * separation * sin + offset * cos);
+    b.creature.stamp(&mut world, bx, by, angle + PI - b.heading);
+
+    let blobs_of = |world: &World| {
+        connected_components(&world.mass_field(), side, side, cfg.threshold).blobs
+    };
+    let start = blobs_of(&world);
+    let start_mass: f32 = start.iter().map(|b| b.mass).sum();
+    let floor = cfg.debris * start_mass;
+    let count = |blobs: &[Blob]| blobs.iter().filter(|b| b.mass >= floor).count();
+    // Contact is the two footprints joining into one blob, which can only be
+    // seen if they start as two.
+    let start_blobs = count(&start);
+    if start_blobs != 2 {
+        let outcome = Outcome::Unseparated { blobs: start_blobs };
+        let (mass_retained, dominance) = (1.0, None);
+        return Collision { angle, offset, outcome, blobs: start_blobs, mass_retained, dominance };
+    }
+    let mut contact = false;
+    for _ in 0..cfg.steps {
+        world.step();
+        contact |= count(&blobs_of(&world)) < 2;
+    }
+    let end = blobs_of(&world);
+    let (blobs, mass) = (count(&end), end.iter().map(|b| b.mass).sum::<f32>());
+    let mass_retained = if start_mass > 0.0 { mass / start_mass } else { 0.0 };
+
+    let outcome = if blobs == 0 || mass_retained < 0.5 {
+        Outcome::Dispersed
+    } else if blobs == 1 {
+        Outcome::Merged
+    } else if blobs >= 3 {
+        Outcome::Split { blobs }
+    } else if contact {
+        Outcome::PassedThrough
+    } else {
+        Outcome::Missed
+    };
+    let dominance = dominance(&world, mean_mu(&a.creature), mean_mu(&b.creature), cfg.threshold);
+    Collision { angle, offset, outcome, blobs, mass_retained, dominance }
+}
+
+/// Run every `(angle, offset)` collision of `cfg`, angle-major, in parallel.
+pub fn sweep(a: &Collider, b: &Collider, cfg: &CollisionConfig) -> Vec<Collision> {
+    let runs: Vec<(f32, f32)> = cfg
+        .angles
+        .iter()
+        .flat_map(|&angle| cfg.offsets.iter().map(move |&offset| (angle, offset)))
+        .collect();
+    let n = runs.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let threads = std::thread::available_parallelism()
+        .map(|x| x.get())
+        .unwrap_or(4)
+        .min(n);
+    let chunk = n.div_ceil(threads);
+    let mut out = Vec::with_capacity(n);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = runs
+            .chunks(chunk)
+            .map(|slice| {
+                s.spawn(move || {
+                    slice
+                        .iter()
+                        .map(|&(angle, offset)| collide(a, b, angle, offset, cfg))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for h in handles {
+            out.extend(h.join().expect("collision thread panicked"));
+        }
+    });
+    out
+}
+
+/// Lay collisions out as a plain-text table, one row per run.
+pub fn table(collisions: &[Collision]) -> String {
+    let mut out = String::from("angle°  offset  outcome       blobs  mass   a-share\n");
+    for c in collisions {
+        let share = c.dominance.map_or("-".to_string(), |d| format!("{d:.2}"));
+        writeln!(
+            out,
+            "{:>6.0}  {:>6.1}  {:<12}  {:>5}  {:>5.2}  {:>7}",
+            c.angle.to_degrees(),
+            c.offset,
+            c.outcome.name(),
+            c.blobs,
+            c.mass_retained,
+            share
+        )
+        .expect("writing to a String");
+    }
+    out
+}
+
+/// Mass-weighted mean `μ` of a creature: its genome's if it carries one,
+/// else its rule's.
+fn mean_mu(creature: &Creature) -> f32 {
+    let Some(genes) = &creature.genes else {
+        return creature.params.growth_mu;
+    };
+    let n = creature.width * creature.height;
+    let (mut sum, mut mass) = (0.0f64, 0.0f64);
+    for (p, g) in genes.iter().enumerate() {
+        let m: f64 = (0..creature.params.channels).map(|c| creature.matter[c * n + p] as f64).sum();
+        sum += m * g.mu as f64;
+        mass += m;
+    }
+    if mass > 0.0 {
+        (sum / mass) as f32
+    } else {
+        creature.params.growth_mu
+    }
+}
+
+/// Share of the above-threshold mass whose `μ` lies nearer `mu_a` than
+/// `mu_b`; `None` without a genome or when the two coincide.
+fn dominance(world: &World, mu_a: f32, mu_b: f32, threshold: f32) -> Option<f32> {
+    let mu = world.mu_field()?;
+    if mu_a == mu_b {
+        return None;
+    }
+    let (mut near_a, mut total) = (0.0f64, 0.0f64);
+    for (&m, &u) in world.mass_field().iter().zip(mu) {
+        if m <= threshold {
+            continue;
+        }
+        total += m as f64;
+        if (u - mu_a).abs() < (u - mu_b).abs() {
+            near_a += m as f64;
+        }
+    }
+    (total > 0.0).then(|| (near_a / total) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_lenia::{FlowLeniaParams, KernelShape};
+
+    /// A small mover: matter chases its own potential, pushed along each
+    /// cell's kernel orientation by a first harmonic.
+    fn mover_rule() -> FlowLeniaParams {
+        FlowLeniaParams {
+            kernel_radius: 7,
+            shape: KernelShape { harmonic: 1, amplitude: 0.6, ..KernelShape::default() },
+            dt: 0.5,
+            growth_mu: 0.45,
+            growth_sigma: 0.2,
+            ..FlowLeniaParams::default()
+        }
+    }
+
+    fn mover() -> Creature {
+        let mut world = World::new(48, 48, mover_rule());
+        world.enable_genome();
+        world.seed_blob(0, 24.0, 24.0, 2.5, 0.9);
+        Creature::extract(&world, 0, 0.05).unwrap()
+    }
+
+    #[test]
+    fn head_on_movers_merge_and_distant_ones_miss() {
+        let quick = ClassifyConfig { steps: 200, settle: 20, max_period: 30, ..Default::default() };
+        let a = Collider::measured(mover(), &quick).expect("the mover translates");
+        // It travels against its kernel orientation, along -x.
+        assert!((a.heading.abs() - PI).abs() < 0.05, "heading {}", a.heading);
+
+        let cfg = CollisionConfig {
+            angles: vec![0.0],
+            offsets: vec![0.0, 20.0],
+            steps: 300,
+            ..Default::default()
+        };
+        let runs = sweep(&a, &a, &cfg);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].outcome, Outcome::Merged, "{:?}", runs[0]);
+        assert_eq!(runs[1].outcome, Outcome::Missed, "{:?}", runs[1]);
+        assert!(runs.iter().all(|r| r.mass_retained > 0.9 && r.dominance.is_none()));
+        let table = table(&runs);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.contains("merged") && table.contains("missed"), "{table}");
+    }
+
+    #[test]
+    fn pairs_that_start_touching_are_not_run() {
+        let a = Collider::new(mover(), PI);
+        let cfg = CollisionConfig { separation: Some(2.0), steps: 50, ..Default::default() };
+        let run = collide(&a, &a, 0.0, 0.0, &cfg);
+        assert_eq!(run.outcome, Outcome::Unseparated { blobs: 1 });
+        assert_eq!(run.blobs, 1);
+    }
+
+    #[test]
+    fn dominance_is_the_mass_share_nearer_a() {
+        let mut world = World::new(64, 64, mover_rule());
+        assert_eq!(dominance(&world, 0.2, 0.1, 0.05), None);
+        world.enable_genome();
+        world.seed_species(20.0, 32.0, 4.0, 0.9, 0.2, 0.02);
+        world.seed_species(44.0, 32.0, 2.0, 0.9, 0.1, 0.02);
+        let share = dominance(&world, 0.2, 0.1, 0.05).unwrap();
+        assert!(share > 0.6 && share < 1.0, "share {share}");
+        let flipped = dominance(&world, 0.1, 0.2, 0.05).unwrap();
+        assert!((share + flipped - 1.0).abs() < 1e-4);
+        assert_eq!(dominance(&world, 0.2, 0.2, 0.05), None);
+    }
+}
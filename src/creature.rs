@@ -0,0 +1,334 @@
+//! Portable creatures: a blob cut out of a Flow-Lenia world, ready to be
+//! transplanted into another.
+//!
+//! The harness finds blobs; a [`Creature`] is one of them made reusable. It
+//! holds the cropped matter patch (every channel), the localized genome under
+//! it if the world carried one (M-γ-1), and the rule parameters it was found
+//! under — enough to drop a search elite into a fresh world for collision,
+//! competition or ecology experiments, or to keep it on disk as RON.
+//!
+//! Stamping places the creature's mass centroid at any point, at any rotation.
+//! Rotated matter is resampled bilinearly and then renormalized per channel,
+//! so a stamp adds exactly the creature's mass to the world. The genome is
+//! rotated with the matter: each cell's kernel orientation `φ` turns by the
+//! stamp angle, so anisotropic creatures with the genome on keep their
+//! heading relative to their body. Without the genome the kernel orientation
+//! is global and cannot turn; only radial rules are then rotation-invariant.
+
+use crate::flow_lenia::{FlowLeniaParams, Genes, World};
+use crate::harness::label_components;
+use std::io;
+use std::path::Path;
+
+/// Cells of halo kept around a blob's above-threshold core: the faint rim of
+/// matter that belongs to the creature but falls under the blob threshold.
+const HALO: usize = 2;
+
+/// A creature cut out of a world: its matter, its genome and its rule.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Creature {
+    /// The rule the creature was found under.
+    pub params: FlowLeniaParams,
+    /// Patch size in cells.
+    pub width: usize,
+    pub height: usize,
+    /// Matter per channel, channel-major, each row-major `width × height`;
+    /// zero outside the creature.
+    pub matter: Vec<f32>,
+    /// Localized genome per patch cell, if the source world carried one.
+    pub genes: Option<Vec<Genes>>,
+    /// Mass centroid in patch coordinates — the point a stamp places.
+    pub center: (f32, f32),
+}
+
+impl Creature {
+    /// Cut blob `blob` out of `world`. Blobs are indexed as
+    /// [`connected_components`](crate::harness::connected_components) orders
+    /// them over the world's mass field at `threshold` (largest first). The
+    /// creature is the blob's cells plus a [`HALO`]-cell rim not claimed by
+    /// another blob. `None` if there is no such blob.
+    pub fn extract(world: &World, blob: usize, threshold: f32) -> Option<Creature> {
+        let (w, h) = (world.width(), world.height());
+        let (comps, labels) = label_components(&world.mass_field(), w, h, threshold);
+        let core = comps.blobs.get(blob)?;
+
+        // The blob's cells, grown by the halo into unclaimed cells.
+        let mut mask: Vec<bool> = labels.iter().map(|&l| l == Some(blob)).collect();
+        for _ in 0..HALO {
+            let prev = mask.clone();
+            for y in 0..h {
+                for x in 0..w {
+                    let i = y * w + x;
+                    if prev[i] || labels[i].is_some() {
+                        continue;
+                    }
+                    mask[i] = (0..9).any(|k| {
+                        let (nx, ny) = ((x + w + k % 3 - 1) % w, (y + h + k / 3 - 1) % h);
+                        prev[ny * w + nx]
+                    });
+                }
+            }
+        }
+
+        // Integer offsets from the centroid's cell, nearest way round the torus.
+        let (bx, by) = (core.cx.floor() as usize % w, core.cy.floor() as usize % h);
+        let delta =
+            |v: usize, b: usize, n: usize| ((v + n + n / 2 - b) % n) as i64 - (n / 2) as i64;
+        let cells: Vec<(usize, i64, i64)> = (0..w * h)
+            .filter(|&i| mask[i])
+            .map(|i| (i, delta(i % w, bx, w), delta(i / w, by, h)))
+            .collect();
+        let span = |of: fn(&(usize, i64, i64)) -> i64| {
+            cells.iter().map(of).fold((i64::MAX, i64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)))
+        };
+        let ((x0, x1), (y0, y1)) = (span(|c| c.1), span(|c| c.2));
+        let (pw, ph) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+        let channels = world.params().channels;
+
+        let mut matter = vec![0.0f32; channels * pw * ph];
+        let (mut mass, mut mx, mut my) = (0.0f64, 0.0f64, 0.0f64);
+        for &(i, dx, dy) in &cells {
+            let (px, py) = ((dx - x0) as usize, (dy - y0) as usize);
+            for c in 0..channels {
+                let v = world.channel(c)[i];
+                matter[c * pw * ph + py * pw + px] = v;
+                mass += v as f64;
+                mx += v as f64 * px as f64;
+                my += v as f64 * py as f64;
+            }
+        }
+        let center = if mass > 0.0 {
+            ((mx / mass) as f32, (my / mass) as f32)
+        } else {
+            (pw as f32 * 0.5, ph as f32 * 0.5)
+        };
+        // The genome under the whole patch; outside the creature it is inert.
+        let genes = world.genome_enabled().then(|| {
+            (0..pw * ph)
+                .map(|p| {
+                    let x = (bx as i64 + x0 + (p % pw) as i64).rem_euclid(w as i64) as usize;
+                    let y = (by as i64 + y0 + (p / pw) as i64).rem_euclid(h as i64) as usize;
+                    world.genes(x, y).expect("genome enabled")
+                })
+                .collect()
+        });
+        let params = world.params().clone();
+        Some(Creature { params, width: pw, height: ph, matter, genes, center })
+    }
+
+    /// Matter of channel `c`, row-major `width × height`.
+    pub fn channel(&self, c: usize) -> &[f32] {
+        let n = self.width * self.height;
+        &self.matter[c * n..(c + 1) * n]
+    }
+
+    /// Total mass over every channel.
+    pub fn mass(&self) -> f64 {
+        self.matter.iter().map(|&v| v as f64).sum()
+    }
+
+    /// Add the creature to `world` with its mass centroid at `(x, y)`, rotated
+    /// by `angle` radians (from `+x` toward `+y`). Exactly the creature's mass
+    /// is added, channel by channel. Where it lands on existing matter, the
+    /// genome is blended mass-weighted, as transport would. A creature with a
+    /// genome switches the world's genome on if needed.
+    ///
+    /// # Panics
+    /// If the world's channel count differs from the creature's.
+    pub fn stamp(&self, world: &mut World, x: f32, y: f32, angle: f32) {
+        let channels = self.params.channels;
+        assert_eq!(
+            world.params().channels,
+            channels,
+            "creature and world channel counts differ"
+        );
+        if self.genes.is_some() && !world.genome_enabled() {
+            world.enable_genome();
+        }
+        let (w, h) = (world.width(), world.height());
+        let (pw, ph) = (self.width, self.height);
+        let (sin, cos) = angle.sin_cos();
+        // Every world cell within the rotated patch's reach, mapped back into
+        // patch coordinates.
+        let reach = ((pw * pw + ph * ph) as f32).sqrt().ceil() as i64 + 1;
+        let (ox, oy) = (x.floor() as i64, y.floor() as i64);
+        // Target cells with their patch-space source point, and the sampled
+        // matter of each (`channels` values per target).
+        let mut landed: Vec<(usize, usize, (f32, f32))> = Vec::new();
+        let mut values: Vec<f32> = Vec::new();
+        let mut sums = vec![0.0f64; channels];
+        for j in -reach..=reach {
+            for i in -reach..=reach {
+                let (rx, ry) = ((ox + i) as f32 - x, (oy + j) as f32 - y);
+                let src =
+                    (cos * rx + sin * ry + self.center.0, cos * ry - sin * rx + self.center.1);
+                let sampled = (0..channels).map(|c| sample_patch(self.channel(c), pw, ph, src));
+                let start = values.len();
+                values.extend(sampled);
+                if values[start..].iter().all(|&v| v <= 0.0) {
+                    values.truncate(start);
+                    continue;
+                }
+                for (s, &v) in sums.iter_mut().zip(&values[start..]) {
+                    *s += v as f64;
+                }
+                let (wx, wy) = ((ox + i).rem_euclid(w as i64), (oy + j).rem_euclid(h as i64));
+                landed.push((wx as usize, wy as usize, src));
+            }
+        }
+        // Renormalize so the stamp adds exactly the creature's mass.
+        let scales: Vec<f32> = (0..channels)
+            .map(|c| {
+                let target: f64 = self.channel(c).iter().map(|&v| v as f64).sum();
+                if sums[c] > 0.0 {
+                    (target / sums[c]) as f32
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        for ((wx, wy, src), values) in landed.into_iter().zip(values.chunks(channels)) {
+            let i = wy * w + wx;
+            let before: f32 = (0..channels).map(|c| world.channel(c)[i]).sum();
+            let mut added = 0.0f32;
+            for (c, (&v, &s)) in values.iter().zip(&scales).enumerate() {
+                world.channel_mut(c)[i] += v * s;
+                added += v * s;
+            }
+            if let Some(genes) = &self.genes {
+                // Nearest patch cell: genes are not interpolated across species.
+                let px = (src.0.round().max(0.0) as usize).min(pw - 1);
+                let py = (src.1.round().max(0.0) as usize).min(ph - 1);
+                let mut new = genes[py * pw + px];
+                new.orientation += angle;
+                let old = world.genes(wx, wy).expect("genome enabled");
+                world.set_genes(wx, wy, blend(old, before.max(0.0), new, added));
+            }
+        }
+    }
+
+    /// Write the creature to `path` as RON.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, text)
+    }
+
+    /// Read a creature saved with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Creature> {
+        let text = std::fs::read_to_string(path)?;
+        ron::de::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Bilinear sample of a `w × h` patch at `(x, y)`, zero outside it.
+fn sample_patch(patch: &[f32], w: usize, h: usize, (x, y): (f32, f32)) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let at = |xi: f32, yi: f32| {
+        if xi < 0.0 || yi < 0.0 || xi >= w as f32 || yi >= h as f32 {
+            0.0
+        } else {
+            patch[yi as usize * w + xi as usize]
+        }
+    };
+    at(x0, y0) * (1.0 - fx) * (1.0 - fy)
+        + at(x0 + 1.0, y0) * fx * (1.0 - fy)
+        + at(x0, y0 + 1.0) * (1.0 - fx) * fy
+        + at(x0 + 1.0, y0 + 1.0) * fx * fy
+}
+
+/// Mass-weighted blend of two genomes meeting in one cell; orientation is
+/// averaged as a unit vector so it wraps correctly.
+fn blend(a: Genes, ma: f32, b: Genes, mb: f32) -> Genes {
+    let total = ma + mb;
+    if total <= 0.0 {
+        return b;
+    }
+    let (wa, wb) = (ma / total, mb / total);
+    let mix = |u: f32, v: f32| wa * u + wb * v;
+    let (sa, ca) = a.orientation.sin_cos();
+    let (sb, cb) = b.orientation.sin_cos();
+    Genes {
+        mu: mix(a.mu, b.mu),
+        sigma: mix(a.sigma, b.sigma),
+        orientation: mix(sa, sb).atan2(mix(ca, cb)),
+        beta: mix(a.beta, b.beta),
+        emit: mix(a.emit, b.emit),
+        sense: mix(a.sense, b.sense),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::connected_components;
+
+    /// A world holding two separate blobs, the first one heavier.
+    fn two_blob_world() -> World {
+        let mut world = World::new(64, 48, FlowLeniaParams::default());
+        world.enable_genome();
+        world.seed_species(20.0, 24.0, 6.0, 0.9, 0.2, 0.02);
+        world.seed_species(50.0, 10.0, 4.0, 0.6, 0.12, 0.015);
+        world
+    }
+
+    #[test]
+    fn extract_crops_one_blob_with_its_genome() {
+        let world = two_blob_world();
+        let threshold = 0.05;
+        let comps = connected_components(&world.mass_field(), 64, 48, threshold);
+        assert_eq!(comps.count(), 2);
+        let big = Creature::extract(&world, 0, threshold).unwrap();
+        let small = Creature::extract(&world, 1, threshold).unwrap();
+        assert!(Creature::extract(&world, 2, threshold).is_none());
+        // The creature holds at least its blob's mass, and far from all of it.
+        assert!(big.mass() >= comps.blobs[0].mass as f64 - 1e-3);
+        assert!(big.mass() < world.total_mass() - small.mass() + 1e-3);
+        let genes = big.genes.as_ref().unwrap();
+        let (cx, cy) = (big.center.0.round() as usize, big.center.1.round() as usize);
+        assert!((genes[cy * big.width + cx].mu - 0.2).abs() < 1e-6);
+        assert!(big.width < 40 && big.height < 40, "{}×{}", big.width, big.height);
+    }
+
+    #[test]
+    fn stamping_preserves_mass_and_places_the_centroid() {
+        let world = two_blob_world();
+        let creature = Creature::extract(&world, 0, 0.05).unwrap();
+        for angle in [0.0f32, 0.7, std::f32::consts::PI] {
+            let mut target = World::new(80, 80, FlowLeniaParams::default());
+            creature.stamp(&mut target, 40.0, 30.0, angle);
+            let drift = (target.total_mass() - creature.mass()).abs() / creature.mass();
+            assert!(drift < 1e-5, "angle {angle}: mass drifted {drift}");
+            let (cx, cy) = target.center_of_mass().unwrap();
+            assert!((cx - 40.0).abs() < 0.5 && (cy - 30.0).abs() < 0.5, "({cx}, {cy})");
+            let mu = target.mu_field().expect("stamp switched the genome on");
+            assert!((mu[30 * 80 + 40] - 0.2).abs() < 1e-5);
+            let orient = target.orientation_field().unwrap();
+            assert!((orient[30 * 80 + 40].sin() - angle.sin()).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn stamping_wraps_and_blends_into_existing_matter() {
+        let creature = Creature::extract(&two_blob_world(), 0, 0.05).unwrap();
+        let mut target = World::new(48, 48, FlowLeniaParams::default());
+        target.enable_genome();
+        target.seed_species(2.0, 2.0, 5.0, 0.9, 0.1, 0.02);
+        let before = target.total_mass();
+        creature.stamp(&mut target, 1.0, 1.0, 0.0);
+        assert!((target.total_mass() - before - creature.mass()).abs() < 1e-3);
+        let mu = target.mu_field().unwrap()[48 + 1];
+        assert!(mu > 0.1 + 1e-3 && mu < 0.2 - 1e-3, "genomes should blend, μ = {mu}");
+    }
+
+    #[test]
+    fn creature_roundtrips_through_disk() {
+        let creature = Creature::extract(&two_blob_world(), 1, 0.05).unwrap();
+        let path = std::env::temp_dir().join(format!("creature-{}.ron", std::process::id()));
+        creature.save(&path).unwrap();
+        let loaded = Creature::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded, creature);
+    }
+}
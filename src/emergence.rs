@@ -2,9 +2,12 @@
 //!
 //! Three complementary measurements:
 //! - Derrida damage-spreading: classifies rules as ordered/critical/chaotic
-//! - Compression complexity: structural complexity of spacetime blocks
+//! - Compression complexity: structural complexity of spacetime blocks (density entropy,
+//!   and LZ76 parsing shared with the continuous harness)
 //! - Shift cross-correlation: detects translating structures without pattern catalog
 
+use std::collections::VecDeque;
+
 use crate::grid::{Coordinate, Grid};
 
 /// Derrida damage-spreading measurement.
@@ -248,6 +251,198 @@ pub fn spacetime_complexity(snapshots: &[Vec<bool>], grid_width: usize) -> Compl
     }
 }
 
+/// How [`ComplexityMeter`] quantizes and blocks a field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompressionConfig {
+    /// Quantization levels (the compressor's alphabet size, ≥ 2).
+    pub levels: u8,
+    /// Field value mapped to the top level; larger values saturate. Fixed so
+    /// frames of a run stay comparable.
+    pub scale: f32,
+    /// Side of a square spatial block, in cells.
+    pub block: usize,
+    /// Consecutive frames stacked into a spacetime block.
+    pub depth: usize,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        CompressionConfig {
+            levels: 8,
+            scale: 1.0,
+            block: 16,
+            depth: 4,
+        }
+    }
+}
+
+/// Compression complexity of one frame in its run (see [`ComplexityMeter`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompressionComplexity {
+    /// Mean normalized LZ complexity of the frame's spatial blocks: 0 for a
+    /// uniform field, about 1 for noise.
+    pub spatial: f32,
+    /// Mean normalized LZ complexity of the spacetime blocks ending at this
+    /// frame: lower than `spatial` when the blocks repeat over time.
+    pub spacetime: f32,
+    /// Mean over spacetime blocks of `4·c·(1 − c)`: 0 for blocks that are
+    /// trivially regular or incompressible, highest for structure in between.
+    pub statistical: f32,
+}
+
+/// Lempel–Ziv (1976) complexity of `symbols`: the number of phrases in its
+/// exhaustive parse, each the shortest extension not seen before
+/// (Kaspar–Schuster algorithm).
+pub fn lz76_complexity(symbols: &[u8]) -> usize {
+    let n = symbols.len();
+    if n < 2 {
+        return n;
+    }
+    let (mut c, mut l, mut i, mut k, mut k_max) = (1, 1, 0, 1, 1);
+    loop {
+        if symbols[i + k - 1] == symbols[l + k - 1] {
+            k += 1;
+            if l + k > n {
+                c += 1;
+                break;
+            }
+        } else {
+            k_max = k_max.max(k);
+            i += 1;
+            if i == l {
+                c += 1;
+                l += k_max;
+                if l + 1 > n {
+                    break;
+                }
+                (i, k, k_max) = (0, 1, 1);
+            } else {
+                k = 1;
+            }
+        }
+    }
+    c
+}
+
+/// [`lz76_complexity`] normalized by its value for a random sequence over an
+/// `alphabet`-letter alphabet, `n / log_alphabet(n)`: about 1 for noise,
+/// toward 0 for regular sequences.
+pub fn normalized_lz(symbols: &[u8], alphabet: usize) -> f32 {
+    let n = symbols.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let c = lz76_complexity(symbols) as f64;
+    (c * (n as f64).ln() / (alphabet.max(2) as f64).ln() / n as f64) as f32
+}
+
+/// Quantize `field` into `cfg.levels` levels over `[0, cfg.scale]`.
+pub fn quantize(field: &[f32], cfg: &CompressionConfig) -> Vec<u8> {
+    let top = cfg.levels.max(2) - 1;
+    let scale = cfg.levels.max(2) as f32 / cfg.scale.max(f32::MIN_POSITIVE);
+    field
+        .iter()
+        .map(|&v| ((v.max(0.0) * scale) as u32).min(top as u32) as u8)
+        .collect()
+}
+
+/// Streams the compression complexity of a run's frames: each observed
+/// field is quantized, cut into `block × block` tiles, and every tile is
+/// parsed by [`lz76_complexity`] alone (spatial) and stacked with the same
+/// tile of the previous `depth − 1` frames (spacetime). Rule-agnostic, so
+/// quantized boolean snapshots of a discrete grid score on the same scale
+/// (see [`compression_complexity`]).
+pub struct ComplexityMeter {
+    w: usize,
+    h: usize,
+    cfg: CompressionConfig,
+    window: VecDeque<Vec<u8>>,
+    /// The complexity curve: one entry per observed frame.
+    pub series: Vec<(usize, CompressionComplexity)>,
+}
+
+impl ComplexityMeter {
+    pub fn new(w: usize, h: usize, cfg: CompressionConfig) -> Self {
+        ComplexityMeter {
+            w,
+            h,
+            cfg,
+            window: Default::default(),
+            series: Vec::new(),
+        }
+    }
+
+    /// Observe `field` at `step` and return its complexity.
+    pub fn observe(&mut self, step: usize, field: &[f32]) -> CompressionComplexity {
+        let frame = quantize(field, &self.cfg);
+        self.window.push_back(frame);
+        while self.window.len() > self.cfg.depth.max(1) {
+            self.window.pop_front();
+        }
+        let side = self.cfg.block.clamp(1, self.w.min(self.h).max(1));
+        let (bx, by) = (self.w / side, self.h / side);
+        let alphabet = self.cfg.levels.max(2) as usize;
+        let (mut spatial, mut spacetime, mut statistical) = (0.0f64, 0.0f64, 0.0f64);
+        let mut tile = Vec::with_capacity(side * side * self.window.len());
+        for j in 0..by {
+            for i in 0..bx {
+                tile.clear();
+                for frame in &self.window {
+                    for y in j * side..(j + 1) * side {
+                        tile.extend_from_slice(&frame[y * self.w + i * side..][..side]);
+                    }
+                }
+                let current = &tile[tile.len() - side * side..];
+                spatial += normalized_lz(current, alphabet) as f64;
+                let c = normalized_lz(&tile, alphabet).min(1.0) as f64;
+                spacetime += c;
+                statistical += 4.0 * c * (1.0 - c);
+            }
+        }
+        let blocks = (bx * by).max(1) as f64;
+        let out = CompressionComplexity {
+            spatial: (spatial / blocks) as f32,
+            spacetime: (spacetime / blocks) as f32,
+            statistical: (statistical / blocks) as f32,
+        };
+        self.series.push((step, out));
+        out
+    }
+}
+
+/// Compression complexity of a sequence of grid snapshots, on the same scale
+/// as the continuous substrate's [`RunSummary`](crate::harness::RunSummary):
+/// each snapshot is fed to a two-level [`ComplexityMeter`] and the per-frame
+/// readings are averaged.
+pub fn compression_complexity(snapshots: &[Vec<bool>], grid_width: usize) -> CompressionComplexity {
+    let Some(first) = snapshots.first() else {
+        return CompressionComplexity::default();
+    };
+    let grid_height = first.len() / grid_width.max(1);
+    let cfg = CompressionConfig {
+        levels: 2,
+        ..CompressionConfig::default()
+    };
+    let mut meter = ComplexityMeter::new(grid_width, grid_height, cfg);
+    let mut sum = [0.0f64; 3];
+    for (t, snap) in snapshots.iter().enumerate() {
+        let field: Vec<f32> = snap
+            .iter()
+            .map(|&alive| if alive { 1.0 } else { 0.0 })
+            .collect();
+        let c = meter.observe(t, &field);
+        sum[0] += c.spatial as f64;
+        sum[1] += c.spacetime as f64;
+        sum[2] += c.statistical as f64;
+    }
+    let n = snapshots.len() as f64;
+    CompressionComplexity {
+        spatial: (sum[0] / n) as f32,
+        spacetime: (sum[1] / n) as f32,
+        statistical: (sum[2] / n) as f32,
+    }
+}
+
 #[derive(Copy, Clone, Debug, Default)]
 pub struct ComplexityResult {
     pub entropy: f32,
@@ -1482,6 +1677,24 @@ mod tests {
         assert_eq!(result.entropy, 0.0);
     }
 
+    #[test]
+    fn compression_complexity_separates_soup_from_empty() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(9);
+        let soup: Vec<Vec<bool>> = (0..8)
+            .map(|_| (0..32 * 32).map(|_| rng.gen_bool(0.5)).collect())
+            .collect();
+        let empty = vec![vec![false; 32 * 32]; 8];
+        let soup = compression_complexity(&soup, 32);
+        let empty = compression_complexity(&empty, 32);
+        assert!(soup.spacetime > 0.8, "{soup:?}");
+        assert!(empty.spacetime < 0.05, "{empty:?}");
+        assert_eq!(
+            compression_complexity(&[], 32),
+            CompressionComplexity::default()
+        );
+    }
+
     #[test]
     fn shift_correlation_static() {
         // A static pattern should have best correlation at (0,0) — no translation
@@ -1489,4 +1702,50 @@ mod tests {
         let result = shift_cross_correlation(&grid, &grid, 8, 8, 3);
         assert!(!result.has_translating_structure);
     }
+
+    #[test]
+    fn lz76_counts_phrases() {
+        // Kaspar & Schuster's worked example: 0·001·10·100·1000·101.
+        let seq: Vec<u8> = "0001101001000101".bytes().map(|b| b - b'0').collect();
+        assert_eq!(lz76_complexity(&seq), 6);
+        assert_eq!(lz76_complexity(&[3; 100]), 2);
+        assert_eq!(lz76_complexity(&[]), 0);
+        assert!(normalized_lz(&[0; 1000], 2) < 0.05);
+    }
+
+    #[test]
+    fn complexity_meter_ranks_uniform_structure_and_noise() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        let (w, h) = (64, 64);
+        let cfg = CompressionConfig::default();
+        let run = |frames: &mut dyn FnMut(usize) -> Vec<f32>| {
+            let mut meter = ComplexityMeter::new(w, h, cfg);
+            for t in 0..6 {
+                meter.observe(t, &frames(t));
+            }
+            assert_eq!(meter.series.len(), 6);
+            meter.series.last().unwrap().1
+        };
+        let uniform = run(&mut |_| vec![0.4; w * h]);
+        let mut rng = StdRng::seed_from_u64(5);
+        let noise = run(&mut |_| (0..w * h).map(|_| rng.gen::<f32>()).collect());
+        // A smooth blob drifting one cell per frame.
+        let blob = run(&mut |t| {
+            (0..w * h)
+                .map(|i| {
+                    let dx = (i % w) as f32 - 20.0 - t as f32;
+                    let dy = (i / w) as f32 - 32.0;
+                    (-(dx * dx + dy * dy) / 200.0).exp()
+                })
+                .collect()
+        });
+        assert!(uniform.spatial < 0.05 && uniform.spacetime < 0.05);
+        assert!(noise.spatial > 0.8 && noise.spacetime > 0.8);
+        assert!(blob.spacetime > uniform.spacetime && blob.spacetime < 0.5 * noise.spacetime);
+        assert!(blob.statistical > noise.statistical && blob.statistical > uniform.statistical);
+        // Frozen noise repeats over time, so its spacetime blocks compress.
+        let frozen: Vec<f32> = (0..w * h).map(|_| rng.gen::<f32>()).collect();
+        let frozen = run(&mut |_| frozen.clone());
+        assert!(frozen.spacetime < 0.5 * frozen.spatial);
+    }
 }
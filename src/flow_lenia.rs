@@ -3,13 +3,16 @@
 //! This retires the discrete B3/S23 lineage. State is a continuous, multi-channel
 //! concentration field `A_i(x) ∈ [0, 1]`. One step:
 //!
-//! 1. Convolve a radial (Lenia) kernel with each channel → neighborhood potential.
+//! 1. Convolve a (by default radial) Lenia kernel with each channel → neighborhood
+//!    potential. [`KernelShape`] optionally adds angular structure.
 //! 2. Map the potential through a Gaussian growth function → an **affinity** field
 //!    `U_i ∈ [-1, 1]`. Unlike classic Lenia, growth is *not added* to the state;
 //!    it only shapes where matter wants to flow.
 //! 3. Assemble a flow vector `F_i = (1-α)∇U_i − α∇A_Σ`, where `A_Σ` is total local
 //!    mass and `α(x)` ramps in the mass-regulation (anti-crowding) term as `A_Σ`
-//!    approaches a critical mass `θ_A`. Gradients via Sobel.
+//!    approaches a critical mass `θ_A`. Gradients via Sobel. With the energy
+//!    economy on, chemotaxis optionally adds `β∇E` (see [`EnergyParams`]); with
+//!    the signal channel on, signal taxis adds a `∇S` term (see [`SignalParams`]).
 //! 4. Transport matter along `F_i` with **reintegration tracking** (bilinear
 //!    scatter): each cell's mass lands on a unit box centered at `p + dt·F`, split
 //!    across the four overlapped cells. The split weights sum to 1, so **total mass
@@ -18,16 +21,24 @@
 //!
 //! Because mass is only moved, never created, total mass is a constant of motion
 //! fixed by the initial condition; structure arises from redistribution alone.
+//! Predation between channels ([`TrophicLink`]) relabels mass in place and keeps
+//! that invariant. An environment field ([`EnvironmentParams`]) can make μ, σ
+//! and dt depend on location; it reshapes the flow, never the mass. Schedules
+//! ([`Schedule`]) do the same over time, varying global parameters step by step.
+//! Optional seeded noise ([`NoiseParams`]) perturbs flow and state, still without
+//! creating or destroying mass.
 //!
 //! This is a CPU reference. It plays the role `sim.rs` played for the discrete
 //! engine: a correct, testable ground truth to validate a later `blade-graphics`
 //! GPU port against.
+//! Sparse stepping ([`World::enable_sparse`]) keeps it affordable for big,
+//! mostly empty worlds by skipping the convolution where there is no matter.
 
 use rand::Rng;
 
 /// One Gaussian ring of a Lenia kernel, expressed in normalized-radius space
 /// (distance from center divided by the kernel radius `R`, in `(0, 1]`).
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct KernelRing {
     /// Ring center, as a fraction of the kernel radius.
     pub peak: f32,
@@ -37,8 +48,53 @@ pub struct KernelRing {
     pub weight: f32,
 }
 
+/// Angular structure of the kernel. The default (all zero) is the classic
+/// radially symmetric Lenia kernel; anything else breaks rotational symmetry in
+/// the *rule*, so directed and rotating motion no longer has to come from the
+/// initial state.
+///
+/// The ring profile at a tap is multiplied by an angular harmonic
+/// `1 + amplitude·cos(k(θ − φ) + twist·r)`, where `θ` is the tap's polar angle,
+/// `r` its normalized radius, and `φ` the orientation. `k = 1` gives a kernel
+/// that senses more mass on one side (directed motion); a nonzero `twist` bends
+/// the harmonic lobes into spiral arms, which makes the kernel chiral (rotation).
+/// Independently, the rings can be displaced along `φ` (`offset`) or squashed
+/// across it (`eccentricity`).
+///
+/// With the genome on (M-γ-1), the harmonic's orientation is localized: each
+/// cell senses through its own `φ(x)`, which advects with the mass (see
+/// [`World::paint_orientation`]). The harmonic is linear in `(cos kφ, sin kφ)`,
+/// so this costs two extra convolutions rather than a kernel per cell. Offset
+/// and eccentricity stay baked in at the global `orientation`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct KernelShape {
+    /// Harmonic order `k` of the angular modulation. `0` = none.
+    pub harmonic: u32,
+    /// Harmonic amplitude, clamped to `[0, 1]` so weights stay non-negative.
+    pub amplitude: f32,
+    /// Phase twist of the harmonic per unit normalized radius, in radians.
+    pub twist: f32,
+    /// Displacement of the ring center along the orientation, as a fraction of
+    /// the kernel radius.
+    pub offset: f32,
+    /// Ring eccentricity in `[0, 1)`: the rings are squashed across the
+    /// orientation axis to `√(1 − e²)` of their radius.
+    pub eccentricity: f32,
+    /// Global orientation `φ`, in radians.
+    pub orientation: f32,
+}
+
+impl KernelShape {
+    /// Whether the kernel is the classic rotationally symmetric one.
+    pub fn is_radial(&self) -> bool {
+        (self.harmonic == 0 || self.amplitude == 0.0)
+            && self.offset == 0.0
+            && self.eccentricity == 0.0
+    }
+}
+
 /// Parameters of a single-species Flow-Lenia world.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct FlowLeniaParams {
     /// Number of concentration channels.
     pub channels: usize,
@@ -46,6 +102,8 @@ pub struct FlowLeniaParams {
     pub kernel_radius: usize,
     /// Rings composing the (shared) radial kernel.
     pub rings: Vec<KernelRing>,
+    /// Angular structure layered on the rings. Default = radially symmetric.
+    pub shape: KernelShape,
     /// Growth-function center `μ`.
     pub growth_mu: f32,
     /// Growth-function width `σ`.
@@ -72,6 +130,7 @@ impl Default for FlowLeniaParams {
             channels: 1,
             kernel_radius: 13,
             rings: vec![KernelRing { peak: 0.5, width: 0.15, weight: 1.0 }],
+            shape: KernelShape::default(),
             growth_mu: 0.15,
             growth_sigma: 0.017,
             dt: 0.1,
@@ -100,7 +159,11 @@ impl Default for FlowLeniaParams {
 ///    mass, every step.
 ///
 /// Energy is replenished by localized renewable **sources** (`add_source`) and
-/// spreads by **diffusion**, so exploitable gradients form. Energy is *not*
+/// spreads by **diffusion**, so exploitable gradients form. Optionally matter can
+/// also *sense* those gradients: **chemotaxis** adds `β·∇E` to every channel's
+/// flow, so matter is drawn up the energy gradient toward food — the minimal
+/// sensor-actuator loop. `β` is global here, or a per-cell gene advected with the
+/// mass when the genome is on ([`World::paint_chemotaxis`]). Energy is *not*
 /// conserved (it is a flow, sourced and dissipated); **mass remains conserved**
 /// across the matter channels exactly as before — gating only reshapes flow.
 /// Explicit death/recycling (matter moving into an inert detritus channel and
@@ -127,6 +190,9 @@ pub struct EnergyParams {
     /// Energy drained per unit of local mass per step (`ΔE −= maintain·A_Σ`): the
     /// standing cost of staying organized.
     pub maintain: f32,
+    /// Chemotactic coupling `β`: the flow gains `β·∇E` before transport. `> 0`
+    /// draws matter up the energy gradient, `< 0` repels it, `0` = blind matter.
+    pub chemotaxis: f32,
 }
 
 impl Default for EnergyParams {
@@ -140,18 +206,22 @@ impl Default for EnergyParams {
             gate_half: 0.5,
             consume: 0.15,
             maintain: 0.004,
+            chemotaxis: 0.0,
         }
     }
 }
 
 /// Internal state of the energy economy: parameters, the live energy field, the
 /// (static) per-cell source injection map, and a diffusion scratch buffer.
+#[derive(Clone)]
 struct Energy {
     params: EnergyParams,
     /// Energy concentration `E(x)`, row-major `w×h`.
     field: Vec<f32>,
     /// Per-cell renewable injection rate `S(x)` added each step (pre-cap).
     source: Vec<f32>,
+    /// Multiplier on every source, driven by [`ScheduleTarget::SourceRate`].
+    source_scale: f32,
     /// Scratch for the out-of-place diffusion pass.
     scratch: Vec<f32>,
 }
@@ -202,11 +272,368 @@ impl Default for DetritusParams {
     }
 }
 
-/// Internal state of the detritus cycle: parameters and the inert detritus field.
+/// Internal state of the detritus cycle: parameters, the inert detritus field,
+/// and its fluxes, last step's and cumulative.
+#[derive(Clone)]
 struct Detritus {
     params: DetritusParams,
     /// Dead mass `Det(x)`, row-major `w×h`. Inert: never flows, grows, or convolves.
     field: Vec<f32>,
+    /// Live matter that died into detritus during the last step.
+    died: f64,
+    /// Detritus that decomposed back into live matter during the last step.
+    recycled: f64,
+    /// `died` and `recycled` summed over every step since the cycle was enabled.
+    total_died: f64,
+    total_recycled: f64,
+}
+
+/// Couplings of the **environment** (F6) — a static or slowly drifting scalar
+/// field `T(x)` (temperature-like, typically in `[-1, 1]`) that makes the local
+/// physics depend on location.
+///
+/// Every coupling is linear in `T` and zero by default, so an environment with
+/// default couplings is inert:
+///
+/// - growth center `μ + mu_shift·T` and width `σ + sigma_shift·T` (on top of the
+///   localized genome, if any — a species' μ is its *preference*, the
+///   environment decides where that preference fits);
+/// - integration step `dt·(1 + dt_scale·T)`, floored at 0 — fast and frozen zones;
+/// - energy upkeep `maintain·(1 + maintain_scale·T)`, floored at 0 — harsh and
+///   mild zones (read only when the energy economy is on).
+///
+/// The pattern translates by `drift` cells per step (bilinear, toroidal), so
+/// zones can move and range shifts can be studied. Build `T` with
+/// [`linear_gradient`], [`patch_field`] or [`noise_field`], or supply any field.
+/// Transport still splats bilinearly, so mass stays conserved exactly.
+#[derive(Clone, Debug, Default)]
+pub struct EnvironmentParams {
+    /// Growth-center shift per unit of `T`.
+    pub mu_shift: f32,
+    /// Growth-width shift per unit of `T`.
+    pub sigma_shift: f32,
+    /// Relative `dt` change per unit of `T`.
+    pub dt_scale: f32,
+    /// Relative maintenance-cost change per unit of `T`.
+    pub maintain_scale: f32,
+    /// Translation of the pattern, in cells per step `(x, y)`.
+    pub drift: (f32, f32),
+}
+
+/// Internal state of the environment: couplings, the pattern as supplied, its
+/// current (drifted) placement, and the accumulated drift.
+#[derive(Clone)]
+struct Environment {
+    params: EnvironmentParams,
+    /// The pattern at zero drift, row-major `w×h`.
+    base: Vec<f32>,
+    /// `T(x)` as it stands this step.
+    field: Vec<f32>,
+    /// Total translation applied to `base` so far, in cells.
+    offset: (f32, f32),
+}
+
+/// A global parameter that a [`Schedule`] can drive over time. Ring targets
+/// index into [`FlowLeniaParams::rings`]; energy targets are ignored while the
+/// energy economy is off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScheduleTarget {
+    GrowthMu,
+    GrowthSigma,
+    Dt,
+    ThetaA,
+    AlphaN,
+    MaxFlow,
+    /// Kernel radius, rounded to whole cells (at least 1).
+    KernelRadius,
+    RingPeak(usize),
+    RingWidth(usize),
+    RingWeight(usize),
+    ShapeAmplitude,
+    ShapeTwist,
+    ShapeOffset,
+    ShapeEccentricity,
+    ShapeOrientation,
+    EnergyDiffusion,
+    EnergyCapacity,
+    EnergyGateHalf,
+    EnergyConsume,
+    EnergyMaintain,
+    EnergyChemotaxis,
+    /// Multiplier on every energy source's injection rate (`1` = as added).
+    SourceRate,
+}
+
+impl ScheduleTarget {
+    /// Whether changing this target changes the kernel (and so needs a rebuild).
+    fn reshapes_kernel(self) -> bool {
+        use ScheduleTarget::*;
+        matches!(
+            self,
+            KernelRadius
+                | RingPeak(_)
+                | RingWidth(_)
+                | RingWeight(_)
+                | ShapeAmplitude
+                | ShapeTwist
+                | ShapeOffset
+                | ShapeEccentricity
+                | ShapeOrientation
+        )
+    }
+}
+
+/// A scalar value as a function of the step count `t` — seasons, regime
+/// shifts and gradual change for global parameters (F7). Attach one to a
+/// [`ScheduleTarget`] with [`World::add_schedule`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Schedule {
+    /// Seasons: `base + amplitude·sin(2π·t/period + phase)`.
+    Sine { base: f32, amplitude: f32, period: f32, phase: f32 },
+    /// Step changes: `initial` until the first `(step, value)` change takes
+    /// effect, then each value from its step on. Changes must be sorted by step
+    /// ([`World::add_schedule`] checks).
+    Steps { initial: f32, changes: Vec<(usize, f32)> },
+    /// A linear ramp from `from` at step `start` to `to` at step `end`, held
+    /// constant before and after.
+    Ramp { from: f32, to: f32, start: usize, end: usize },
+}
+
+impl Schedule {
+    /// The same schedule with every value multiplied by `factor` (timing kept).
+    pub fn scaled(&self, factor: f32) -> Schedule {
+        match *self {
+            Schedule::Sine { base, amplitude, period, phase } => Schedule::Sine {
+                base: base * factor,
+                amplitude: amplitude * factor,
+                period,
+                phase,
+            },
+            Schedule::Steps { initial, ref changes } => Schedule::Steps {
+                initial: initial * factor,
+                changes: changes.iter().map(|&(at, v)| (at, v * factor)).collect(),
+            },
+            Schedule::Ramp { from, to, start, end } => {
+                Schedule::Ramp { from: from * factor, to: to * factor, start, end }
+            }
+        }
+    }
+
+    /// The scheduled value at step `t`.
+    pub fn value(&self, t: usize) -> f32 {
+        match *self {
+            Schedule::Sine { base, amplitude, period, phase } => {
+                let theta = std::f32::consts::TAU * t as f32 / period.max(f32::EPSILON) + phase;
+                base + amplitude * theta.sin()
+            }
+            Schedule::Steps { initial, ref changes } => changes
+                .iter()
+                .take_while(|&&(at, _)| at <= t)
+                .last()
+                .map_or(initial, |&(_, v)| v),
+            Schedule::Ramp { from, to, start, end } => {
+                if end <= start {
+                    return if t < start { from } else { to };
+                }
+                let f = (t as f32 - start as f32) / (end - start) as f32;
+                from + (to - from) * f.clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+/// Parameters of **thermal noise** (F8) — seeded stochasticity in the otherwise
+/// deterministic step.
+///
+/// Two independent terms, both off at zero:
+///
+/// - **Flow noise.** Each cell's flow vector gains an isotropic Gaussian kick
+///   of standard deviation `flow` per component before transport. Matter is
+///   still only moved, so mass is conserved exactly.
+/// - **Exchange noise.** After transport, every pair of 4-neighbors trades a
+///   random amount of matter, `ξ·exchange·min(A_i, A_j)/4` with `ξ ∈ [-1, 1]`:
+///   what one cell loses the other gains, and no cell can go negative. The
+///   genome does not ride along; exchange is jitter, not migration.
+///
+/// Randomness is counter-based: each draw is a hash of `(seed, step, channel,
+/// cell)` rather than the next output of a stateful generator, so the noise
+/// stream is fully determined by the seed and [`World::time`]. A cloned
+/// snapshot therefore replays the same noise, and replicates of one initial
+/// condition differ only by seed ([`World::set_noise_seed`]).
+#[derive(Clone, Debug, Default)]
+pub struct NoiseParams {
+    /// Seed of the noise stream.
+    pub seed: u64,
+    /// Standard deviation of the per-component flow kick.
+    pub flow: f32,
+    /// Strength of the pairwise exchange, in `[0, 1]`.
+    pub exchange: f32,
+}
+
+/// One **trophic link** (F5): channel `predator` eats channel `prey` where the
+/// two overlap.
+///
+/// Consumption follows mass action, the Lotka–Volterra interaction term: each
+/// step `Δ = rate·A_pred·A_prey` (capped at the prey present) moves from the prey
+/// channel to the predator channel in place, so `A_Σ` — and therefore total mass
+/// — is untouched; predation only relabels matter. With the energy economy on,
+/// each unit eaten also releases `energy_yield` into `E` (capped at capacity),
+/// the metabolic payoff of a meal. Predators grow only where they overlap prey,
+/// and prey persist only where predators do not reach — trophic dynamics
+/// (oscillations, overshoot, extinction) fall out of the physics rather than a
+/// fitness function. Links apply in order each step; cycles (rock–paper–scissors)
+/// are allowed. Enable with [`World::enable_trophic`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrophicLink {
+    /// Channel that gains mass.
+    pub predator: usize,
+    /// Channel that loses mass.
+    pub prey: usize,
+    /// Mass-action conversion rate per step.
+    pub rate: f32,
+    /// Energy released per unit of prey mass converted (read only when the
+    /// energy economy is on).
+    pub energy_yield: f32,
+}
+
+/// Parameters of the **signal channel** (F4) — a diffusible chemical organisms
+/// secrete and sense, the substrate for communication.
+///
+/// Off by default. When enabled (`World::enable_signal`), a scalar signal field
+/// `S(x)` sits alongside matter and energy:
+///
+/// 1. **Production.** Every cell secretes `emit·A_Σ` per step — proportional to
+///    local mass, at a rate that is a localized gene when the genome is on
+///    ([`World::paint_signal_genes`]), else the global `production`.
+/// 2. **Transport.** `S` diffuses (explicit Laplacian) and decays by a fixed
+///    fraction per step, so a signal reports *recent, nearby* mass.
+/// 3. **Response.** Scaled by a per-cell `sense` gene (1 without the genome),
+///    signal shifts the local growth center, `μ → μ + sense·growth_shift·S`,
+///    and adds a taxis term `sense·taxis·∇S` to the flow.
+///
+/// Like energy, the signal is not conserved, and it never moves matter except
+/// through the flow — mass stays conserved exactly. Emitters and responders are
+/// separate genes, so quorum sensing (respond to your own kind's density) and
+/// signalling between species are both expressible.
+#[derive(Clone, Debug)]
+pub struct SignalParams {
+    /// Secretion per unit local mass per step (the global `emit` rate).
+    pub production: f32,
+    /// Diffusion coefficient for the signal Laplacian, per step (`≤ 0.25`).
+    pub diffusion: f32,
+    /// Fraction of the signal lost per step.
+    pub decay: f32,
+    /// Growth-center shift per unit of sensed signal.
+    pub growth_shift: f32,
+    /// Flow coupling to the signal gradient: `> 0` attracts, `< 0` repels.
+    pub taxis: f32,
+}
+
+impl Default for SignalParams {
+    /// A short-range, fast-decaying signal (length scale a few cells) with no
+    /// response wired in — enabling it is inert until a response is chosen.
+    fn default() -> Self {
+        Self { production: 0.02, diffusion: 0.2, decay: 0.05, growth_shift: 0.0, taxis: 0.0 }
+    }
+}
+
+/// Internal state of the signal channel: parameters, the live signal field,
+/// and a diffusion scratch buffer.
+#[derive(Clone)]
+struct Signal {
+    params: SignalParams,
+    /// Signal concentration `S(x)`, row-major `w×h`.
+    field: Vec<f32>,
+    /// Scratch for the out-of-place diffusion pass.
+    scratch: Vec<f32>,
+}
+
+/// A field the runtime validator inspects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvariantField {
+    /// A matter channel, by index.
+    Matter(usize),
+    Energy,
+    Detritus,
+    Signal,
+}
+
+/// A broken runtime invariant, as reported by [`World::validate`] and the
+/// per-step validator ([`World::enable_validation`]). `step` is the number of
+/// steps taken when the violation was found.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvariantViolation {
+    /// A NaN or infinite value at cell `(x, y)`.
+    NonFinite { field: InvariantField, x: usize, y: usize, step: usize, value: f32 },
+    /// A negative concentration at cell `(x, y)`, beyond rounding slack.
+    Negative { field: InvariantField, x: usize, y: usize, step: usize, value: f32 },
+    /// Conserved mass (matter plus detritus) drifted past the tolerance.
+    MassDrift { step: usize, expected: f64, actual: f64 },
+}
+
+impl std::fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Self::NonFinite { field, x, y, step, value } => {
+                write!(f, "step {step}: non-finite {field:?} {value} at ({x}, {y})")
+            }
+            Self::Negative { field, x, y, step, value } => {
+                write!(f, "step {step}: negative {field:?} {value} at ({x}, {y})")
+            }
+            Self::MassDrift { step, expected, actual } => {
+                write!(f, "step {step}: conserved mass drifted from {expected} to {actual}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// Side of the square tiles sparse stepping tracks activity over, in cells.
+const SPARSE_TILE: usize = 16;
+
+/// Negative values above this are rounding noise, not a broken invariant.
+const NEGATIVE_SLACK: f32 = 1e-6;
+
+/// Per-step validator state: the tolerance, the conserved-mass baseline (taken
+/// at the first validated step), and the first violation found.
+#[derive(Clone)]
+struct Validation {
+    mass_tolerance: f64,
+    baseline: Option<f64>,
+    violation: Option<InvariantViolation>,
+}
+
+/// Sparse-stepping state: the emptiness threshold and, per channel, which
+/// tiles the last step convolved (channel-major, row-major tiles). `occupied`
+/// marks the tiles holding any matter at all, for the step in progress only:
+/// the transport pass takes it, and runs dense when it is missing.
+#[derive(Clone)]
+struct Sparse {
+    epsilon: f32,
+    active: Vec<bool>,
+    occupied: Vec<bool>,
+}
+
+/// Intermediate fields of the last step, recorded when diagnostics are on.
+/// Per-channel fields are channel-major like the state; per-cell fields are
+/// row-major `w×h`.
+#[derive(Clone)]
+struct Diagnostics {
+    /// Neighborhood potential (kernel convolution), per channel.
+    potential: Vec<f32>,
+    /// Energy gate `g(E)` applied to affinity (1 without the energy economy).
+    gate: Vec<f32>,
+    /// Flow vector `F` before the `max_flow` clamp, per channel. Zero where the
+    /// channel holds no mass (no flow is computed there).
+    flow_x: Vec<f32>,
+    flow_y: Vec<f32>,
+    /// Mass-regulation ramp `α(x)`.
+    alpha: Vec<f32>,
+    /// Whether `max_flow` clamped the displacement, per channel.
+    clamped: Vec<bool>,
+    /// Matter the flow acted on (the pre-transport state), per channel.
+    mass: Vec<f32>,
 }
 
 /// A precomputed kernel tap: an integer offset and its normalized weight.
@@ -217,6 +644,46 @@ struct Tap {
     w: f32,
 }
 
+/// A tap of the harmonic kernel basis: the ring profile `w` and its products
+/// with `cos`/`sin` of the harmonic phase `kθ + twist·r`.
+#[derive(Clone, Copy)]
+struct HarmonicTap {
+    dx: i32,
+    dy: i32,
+    w: f32,
+    wc: f32,
+    ws: f32,
+}
+
+/// The kernel decomposed so the harmonic's orientation can vary per cell:
+/// `K_φ = base + a·(cos kφ·cos_part + sin kφ·sin_part)`, renormalized per cell
+/// by the same combination of the part sums.
+#[derive(Clone)]
+struct HarmonicBasis {
+    taps: Vec<HarmonicTap>,
+    /// Sums of the `cos` and `sin` parts (the base sums to 1).
+    sum_c: f32,
+    sum_s: f32,
+}
+
+/// The localized genome (M-γ-1) at one cell: every parameter its matter
+/// carries along. Read and written with [`World::genes`] / [`World::set_genes`].
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Genes {
+    /// Growth center `μ`.
+    pub mu: f32,
+    /// Growth width `σ`.
+    pub sigma: f32,
+    /// Kernel orientation `φ`, in radians.
+    pub orientation: f32,
+    /// Chemotactic coupling `β`.
+    pub beta: f32,
+    /// Signal secretion rate.
+    pub emit: f32,
+    /// Signal response strength.
+    pub sense: f32,
+}
+
 /// Localized parameters (M-γ-1) — the "genome" carried by the matter itself.
 ///
 /// When enabled (`World::enable_genome`), the growth center `μ` and width `σ`
@@ -235,26 +702,46 @@ struct Tap {
 /// low-pass filter on the gene pool; left unchecked it homogenizes species into
 /// their blend. `mu_stats` exposes the mass-weighted mean/variance so the collapse
 /// is measurable rather than assumed.
+#[derive(Clone)]
 struct Genome {
     /// Per-cell growth center `μ`, row-major `w×h`.
     mu: Vec<f32>,
     /// Per-cell growth width `σ`.
     sigma: Vec<f32>,
+    /// Per-cell kernel orientation `φ`, read when the kernel has a harmonic.
+    orient: Vec<f32>,
+    /// Per-cell chemotactic coupling `β`, read when the energy economy is on.
+    beta: Vec<f32>,
+    /// Per-cell signal secretion rate and response strength, read when the
+    /// signal channel is on.
+    emit: Vec<f32>,
+    sense: Vec<f32>,
     /// Advection accumulators: mass-weighted sums scattered during transport,
     /// divided by the new per-cell mass to recover the averaged parameter.
+    /// Orientation is averaged as a unit vector so it wraps correctly.
     mu_acc: Vec<f32>,
     sigma_acc: Vec<f32>,
+    beta_acc: Vec<f32>,
+    emit_acc: Vec<f32>,
+    sense_acc: Vec<f32>,
+    orient_cos_acc: Vec<f32>,
+    orient_sin_acc: Vec<f32>,
 }
 
 /// A continuous, mass-conserving Flow-Lenia world.
+#[derive(Clone)]
 pub struct World {
     w: usize,
     h: usize,
     params: FlowLeniaParams,
     /// Channel-major concentration: `a[c * w * h + y * w + x]`.
     a: Vec<f32>,
-    /// Shared radial kernel, as normalized taps (weights sum to 1).
+    /// Shared kernel at the global orientation, as normalized taps (weights sum
+    /// to 1).
     kernel: Vec<Tap>,
+    /// Harmonic basis for per-cell orientation; `None` if the kernel has no
+    /// angular harmonic.
+    basis: Option<HarmonicBasis>,
     // Scratch buffers reused across steps to avoid per-step allocation.
     potential: Vec<f32>, // per-channel affinity U_i
     total: Vec<f32>,     // A_Σ (pre-transport, this step)
@@ -263,6 +750,29 @@ pub struct World {
     energy: Option<Energy>,
     /// Optional closed-loop detritus cycle (M-γ-3). `None` = no death/recycling.
     detritus: Option<Detritus>,
+    /// Optional diffusible signal channel (F4). `None` = no communication.
+    signal: Option<Signal>,
+    /// Predator–prey coupling between matter channels (F5). Empty = channels
+    /// interact only through anti-crowding.
+    trophic: Vec<TrophicLink>,
+    /// Optional environment field (F6). `None` = uniform physics.
+    environment: Option<Environment>,
+    /// Time-varying global parameters (F7), applied at the start of each step.
+    schedules: Vec<(ScheduleTarget, Schedule)>,
+    /// Runtime invariant checks after each step; `None` = unchecked.
+    validation: Option<Validation>,
+    /// Intermediate fields of the last step; `None` unless diagnostics are on.
+    diagnostics: Option<Diagnostics>,
+    /// Sparse-stepping activity tracking; `None` = dense.
+    sparse: Option<Sparse>,
+    /// Furthest any kernel tap reaches along either axis, in cells.
+    reach: usize,
+    /// Optional thermal noise (F8). `None` = deterministic.
+    noise: Option<NoiseParams>,
+    /// Steps taken so far — the clock schedules and noise read.
+    time: usize,
+    /// How many times the kernel has been built (one at creation).
+    kernel_builds: usize,
     /// Optional localized parameters (M-γ-1). `None` = single global rule.
     genome: Option<Genome>,
 }
@@ -272,6 +782,8 @@ impl World {
     pub fn new(w: usize, h: usize, params: FlowLeniaParams) -> Self {
         assert!(w > 0 && h > 0 && params.channels > 0);
         let kernel = build_kernel(&params);
+        let basis = build_harmonic_basis(&params);
+        let reach = kernel_reach(&kernel, basis.as_ref());
         let cells = w * h;
         World {
             a: vec![0.0; cells * params.channels],
@@ -280,8 +792,20 @@ impl World {
             scratch: vec![0.0; cells],
             energy: None,
             detritus: None,
+            signal: None,
+            trophic: Vec::new(),
+            environment: None,
+            schedules: Vec::new(),
+            noise: None,
+            diagnostics: None,
+            sparse: None,
+            reach,
+            validation: None,
+            time: 0,
+            kernel_builds: 1,
             genome: None,
             kernel,
+            basis,
             w,
             h,
             params,
@@ -300,16 +824,249 @@ impl World {
         &self.params
     }
 
+    /// Steps taken since the world was created.
+    pub fn time(&self) -> usize {
+        self.time
+    }
+
+    /// Replace the global parameters mid-run. The kernel is rebuilt only if its
+    /// radius, rings or shape actually changed.
+    ///
+    /// # Panics
+    /// If `params` changes the number of channels.
+    pub fn set_params(&mut self, params: FlowLeniaParams) {
+        assert_eq!(params.channels, self.params.channels, "channel count is fixed at creation");
+        let reshaped = params.kernel_radius != self.params.kernel_radius
+            || params.rings != self.params.rings
+            || params.shape != self.params.shape;
+        self.params = params;
+        if reshaped {
+            self.kernel = build_kernel(&self.params);
+            self.basis = build_harmonic_basis(&self.params);
+            self.reach = kernel_reach(&self.kernel, self.basis.as_ref());
+            self.kernel_builds += 1;
+        }
+    }
+
+    /// A copy of this world resampled to a `w × h` grid with kernel radius
+    /// `kernel_radius` — promote a creature found cheaply to a high-resolution
+    /// showcase, or check how its behavior fares under a change of scale. The
+    /// two are separate inputs: the fields are stretched onto the new grid by
+    /// `w / width()` and `h / height()`, while the physics is rescaled by the
+    /// kernel factor `s = kernel_radius / params().kernel_radius`. Scaling both
+    /// by the same factor is a change of resolution.
+    ///
+    /// Concentrations are densities, so each matter channel and the detritus
+    /// field is resampled as such (box average when shrinking, linear
+    /// interpolation when growing), then renormalized so its mass in kernel
+    /// units, `Σ / R²`, is preserved exactly: `total_mass()` (and the detritus
+    /// total) of the copy is the original's times `s²`, up to `f32` rounding. The growth window and `θ_A`
+    /// see the same concentrations, so a creature promoted by a change of
+    /// resolution keeps its behavior. Energy, sources, signal, environment and
+    /// genome fields are intensive and resampled as-is (orientation as a unit
+    /// vector).
+    ///
+    /// The physics is rescaled so one step still moves matter the same distance
+    /// in kernel radii: gradients shrink by `1/s`, so `dt` scales by `s²` and
+    /// `max_flow` by `s`. Diffusion coefficients scale by `s²` and are capped at
+    /// the stable `0.25`; flow noise scales by `1/s`. Environment drift follows
+    /// the grid. Schedules on those targets are rescaled too. Diagnostics and
+    /// validation restart fresh in the copy.
+    pub fn rescaled(&self, w: usize, h: usize, kernel_radius: usize) -> World {
+        assert!(w > 0 && h > 0 && kernel_radius > 0);
+        let (ow, oh) = (self.w, self.h);
+        let sx = w as f32 / ow as f32;
+        let sy = h as f32 / oh as f32;
+        let scale = kernel_radius as f32 / self.params.kernel_radius as f32;
+        let area = (kernel_radius as f64 / self.params.kernel_radius as f64).powi(2);
+        let diffusion = |d: f32| (d * scale * scale).min(0.25);
+        let intensive = |f: &[f32]| resample(f, ow, oh, w, h);
+        let extensive = |f: &[f32]| {
+            let mut out = resample(f, ow, oh, w, h);
+            let (before, after): (f64, f64) = (
+                f.iter().map(|&v| v as f64).sum(),
+                out.iter().map(|&v| v as f64).sum(),
+            );
+            if after > 0.0 {
+                let k = (before * area / after) as f32;
+                out.iter_mut().for_each(|v| *v *= k);
+            }
+            out
+        };
+
+        let mut params = self.params.clone();
+        params.kernel_radius = kernel_radius;
+        params.dt *= scale * scale;
+        params.max_flow *= scale;
+        let mut world = World::new(w, h, params);
+        for c in 0..self.params.channels {
+            let field = extensive(self.channel(c));
+            world.channel_mut(c).copy_from_slice(&field);
+        }
+        world.energy = self.energy.as_ref().map(|e| {
+            let mut params = e.params.clone();
+            params.diffusion = diffusion(params.diffusion);
+            Energy {
+                params,
+                field: intensive(&e.field),
+                source: intensive(&e.source),
+                source_scale: e.source_scale,
+                scratch: vec![0.0; w * h],
+            }
+        });
+        world.detritus = self
+            .detritus
+            .as_ref()
+            .map(|d| Detritus { params: d.params.clone(), field: extensive(&d.field), ..*d });
+        world.signal = self.signal.as_ref().map(|sig| {
+            let mut params = sig.params.clone();
+            params.diffusion = diffusion(params.diffusion);
+            Signal { params, field: intensive(&sig.field), scratch: vec![0.0; w * h] }
+        });
+        world.trophic = self.trophic.clone();
+        world.environment = self.environment.as_ref().map(|env| {
+            let mut params = env.params.clone();
+            params.drift = (params.drift.0 * sx, params.drift.1 * sy);
+            Environment {
+                params,
+                base: intensive(&env.base),
+                field: intensive(&env.field),
+                offset: (env.offset.0 * sx, env.offset.1 * sy),
+            }
+        });
+        world.schedules = self
+            .schedules
+            .iter()
+            .map(|(target, schedule)| {
+                let factor = match target {
+                    ScheduleTarget::Dt => scale * scale,
+                    ScheduleTarget::MaxFlow | ScheduleTarget::KernelRadius => scale,
+                    _ => 1.0,
+                };
+                (*target, schedule.scaled(factor))
+            })
+            .collect();
+        world.noise =
+            self.noise.as_ref().map(|n| NoiseParams { flow: n.flow / scale, ..n.clone() });
+        if let Some(g) = &self.genome {
+            world.enable_genome();
+            let ng = world.genome.as_mut().unwrap();
+            ng.mu = intensive(&g.mu);
+            ng.sigma = intensive(&g.sigma);
+            ng.beta = intensive(&g.beta);
+            ng.emit = intensive(&g.emit);
+            ng.sense = intensive(&g.sense);
+            let cos: Vec<f32> = g.orient.iter().map(|a| a.cos()).collect();
+            let sin: Vec<f32> = g.orient.iter().map(|a| a.sin()).collect();
+            ng.orient = intensive(&sin)
+                .into_iter()
+                .zip(intensive(&cos))
+                .map(|(s, c)| s.atan2(c))
+                .collect();
+        }
+        if let Some(sparse) = &self.sparse {
+            world.enable_sparse(sparse.epsilon);
+        }
+        if self.diagnostics.is_some() {
+            world.enable_diagnostics();
+        }
+        if let Some(v) = &self.validation {
+            world.enable_validation(v.mass_tolerance);
+        }
+        world.time = self.time;
+        world
+    }
+
+    /// Drive a global parameter with a schedule (F7). Schedules are evaluated
+    /// at the current [`time`](Self::time) before every step, in the order
+    /// added; a later schedule on the same target wins.
+    ///
+    /// # Panics
+    /// If a ring target names a ring the kernel does not have, or a
+    /// [`Schedule::Steps`] lists its changes out of step order.
+    pub fn add_schedule(&mut self, target: ScheduleTarget, schedule: Schedule) {
+        use ScheduleTarget::{RingPeak, RingWeight, RingWidth};
+        if let RingPeak(i) | RingWidth(i) | RingWeight(i) = target {
+            assert!(i < self.params.rings.len(), "schedule targets missing ring {i}");
+        }
+        if let Schedule::Steps { changes, .. } = &schedule {
+            assert!(
+                changes.windows(2).all(|p| p[0].0 <= p[1].0),
+                "schedule step changes must be sorted by step"
+            );
+        }
+        self.schedules.push((target, schedule));
+    }
+
+    /// Drop every schedule, freezing the parameters at their current values.
+    pub fn clear_schedules(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Write every schedule's value for the current step into the parameters.
+    fn apply_schedules(&mut self) {
+        if self.schedules.is_empty() {
+            return;
+        }
+        let t = self.time;
+        let mut params = self.params.clone();
+        let mut reshaped = false;
+        for (target, schedule) in &self.schedules {
+            let v = schedule.value(t);
+            reshaped |= target.reshapes_kernel();
+            match *target {
+                ScheduleTarget::GrowthMu => params.growth_mu = v,
+                ScheduleTarget::GrowthSigma => params.growth_sigma = v.max(1e-4),
+                ScheduleTarget::Dt => params.dt = v.max(0.0),
+                ScheduleTarget::ThetaA => params.theta_a = v,
+                ScheduleTarget::AlphaN => params.alpha_n = v,
+                ScheduleTarget::MaxFlow => params.max_flow = v.max(0.0),
+                ScheduleTarget::KernelRadius => params.kernel_radius = v.round().max(1.0) as usize,
+                ScheduleTarget::RingPeak(i) => params.rings[i].peak = v,
+                ScheduleTarget::RingWidth(i) => params.rings[i].width = v,
+                ScheduleTarget::RingWeight(i) => params.rings[i].weight = v,
+                ScheduleTarget::ShapeAmplitude => params.shape.amplitude = v,
+                ScheduleTarget::ShapeTwist => params.shape.twist = v,
+                ScheduleTarget::ShapeOffset => params.shape.offset = v,
+                ScheduleTarget::ShapeEccentricity => params.shape.eccentricity = v,
+                ScheduleTarget::ShapeOrientation => params.shape.orientation = v,
+                energy_target => {
+                    let Some(e) = self.energy.as_mut() else { continue };
+                    match energy_target {
+                        ScheduleTarget::EnergyDiffusion => e.params.diffusion = v,
+                        ScheduleTarget::EnergyCapacity => e.params.capacity = v,
+                        ScheduleTarget::EnergyGateHalf => e.params.gate_half = v,
+                        ScheduleTarget::EnergyConsume => e.params.consume = v,
+                        ScheduleTarget::EnergyMaintain => e.params.maintain = v,
+                        ScheduleTarget::EnergyChemotaxis => e.params.chemotaxis = v,
+                        _ => e.source_scale = v.max(0.0),
+                    }
+                }
+            }
+        }
+        if reshaped {
+            self.set_params(params);
+        } else {
+            // Nothing kernel-shaped is scheduled: skip the comparison entirely.
+            self.params = params;
+        }
+    }
+
     /// Enable the energy economy (M-γ-2) with the given parameters. The energy
     /// field starts empty; add sources with [`add_source`](Self::add_source) and
     /// optionally an initial charge with [`charge_energy`](Self::charge_energy).
     /// Idempotent-ish: re-enabling replaces the parameters but resets the field.
+    /// With the genome on, its chemotaxis field is reset to the new global `β`.
     pub fn enable_energy(&mut self, params: EnergyParams) {
         let cells = self.w * self.h;
+        if let Some(g) = self.genome.as_mut() {
+            g.beta.iter_mut().for_each(|b| *b = params.chemotaxis);
+        }
         self.energy = Some(Energy {
             params,
             field: vec![0.0; cells],
             source: vec![0.0; cells],
+            source_scale: 1.0,
             scratch: vec![0.0; cells],
         });
     }
@@ -380,7 +1137,14 @@ impl World {
             self.params.channels, 1,
             "detritus recycling (M-γ-3) is single matter channel in v0"
         );
-        self.detritus = Some(Detritus { params, field: vec![0.0; self.w * self.h] });
+        self.detritus = Some(Detritus {
+            params,
+            field: vec![0.0; self.w * self.h],
+            died: 0.0,
+            recycled: 0.0,
+            total_died: 0.0,
+            total_recycled: 0.0,
+        });
     }
 
     /// Whether the detritus cycle (M-γ-3) is active.
@@ -401,9 +1165,374 @@ impl World {
             .map(|d| d.field.iter().map(|&v| v as f64).sum())
     }
 
+    /// Matter that died into detritus and matter that decomposed back into the
+    /// live channel during the last step, as `(died, recycled)` — the turnover
+    /// of the cycle. `None` if M-γ-3 is disabled.
+    pub fn detritus_flux(&self) -> Option<(f64, f64)> {
+        self.detritus.as_ref().map(|d| (d.died, d.recycled))
+    }
+
+    /// [`detritus_flux`](Self::detritus_flux) summed over every step since the
+    /// cycle was enabled. Differences between two readings give the turnover
+    /// over the steps between them, however many that is.
+    pub fn detritus_totals(&self) -> Option<(f64, f64)> {
+        self.detritus.as_ref().map(|d| (d.total_died, d.total_recycled))
+    }
+
+    /// Couple matter channels with **trophic links** (F5), replacing any previous
+    /// set. See [`TrophicLink`]. An empty list switches predation off.
+    ///
+    /// # Panics
+    /// If a link names a channel the world does not have, or a channel eating
+    /// itself.
+    pub fn enable_trophic(&mut self, links: Vec<TrophicLink>) {
+        let channels = self.params.channels;
+        for l in &links {
+            assert!(
+                l.predator < channels && l.prey < channels,
+                "trophic link {}→{} out of range for {channels} channels",
+                l.prey,
+                l.predator
+            );
+            assert_ne!(l.predator, l.prey, "a channel cannot prey on itself");
+        }
+        self.trophic = links;
+    }
+
+    /// The active trophic links (empty when predation is off).
+    pub fn trophic_links(&self) -> &[TrophicLink] {
+        &self.trophic
+    }
+
+    /// Enable the **environment** (F6): the scalar field `field` (row-major
+    /// `w×h`) modulates local physics through the couplings in `params`. See
+    /// [`EnvironmentParams`]. Replaces any previous environment.
+    ///
+    /// # Panics
+    /// If `field` does not have one value per cell.
+    pub fn enable_environment(&mut self, params: EnvironmentParams, field: Vec<f32>) {
+        assert_eq!(field.len(), self.w * self.h, "environment field must cover every cell");
+        self.environment =
+            Some(Environment { params, base: field.clone(), field, offset: (0.0, 0.0) });
+    }
+
+    /// Whether an environment field is active.
+    pub fn environment_enabled(&self) -> bool {
+        self.environment.is_some()
+    }
+
+    /// Read-only view of the environment field `T(x)` as it stands now
+    /// (including drift), or `None` if there is no environment.
+    pub fn environment_field(&self) -> Option<&[f32]> {
+        self.environment.as_ref().map(|e| e.field.as_slice())
+    }
+
+    /// Replace the environment pattern, keeping the couplings and resetting the
+    /// drift — for environments scripted from outside. No-op without one.
+    ///
+    /// # Panics
+    /// If `field` does not have one value per cell.
+    pub fn set_environment_field(&mut self, field: Vec<f32>) {
+        assert_eq!(field.len(), self.w * self.h, "environment field must cover every cell");
+        if let Some(env) = self.environment.as_mut() {
+            env.base = field.clone();
+            env.field = field;
+            env.offset = (0.0, 0.0);
+        }
+    }
+
+    /// Validate invariants after every step: all fields finite, matter, energy
+    /// and detritus non-negative, and conserved mass (matter plus detritus)
+    /// within `mass_tolerance` relative drift of its value before the first
+    /// validated step. The first violation is latched — see
+    /// [`violation`](Self::violation) and [`try_step`](Self::try_step) — and
+    /// checking stops there. Re-enabling clears it and retakes the baseline.
+    pub fn enable_validation(&mut self, mass_tolerance: f64) {
+        self.validation = Some(Validation { mass_tolerance, baseline: None, violation: None });
+    }
+
+    /// The first invariant violation the per-step validator found, if any.
+    pub fn violation(&self) -> Option<&InvariantViolation> {
+        self.validation.as_ref().and_then(|v| v.violation.as_ref())
+    }
+
+    /// Step once and report the validator's verdict. Without validation
+    /// enabled this is [`step`](Self::step) and always `Ok`.
+    pub fn try_step(&mut self) -> Result<(), InvariantViolation> {
+        self.step();
+        match self.violation() {
+            Some(v) => Err(v.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Matter plus detritus — the quantity every layer conserves.
+    fn conserved_mass(&self) -> f64 {
+        self.total_mass() + self.total_detritus().unwrap_or(0.0)
+    }
+
+    /// Check the pointwise invariants now: every field finite, and matter,
+    /// energy and detritus non-negative. Reports the first offending cell.
+    pub fn validate(&self) -> Result<(), InvariantViolation> {
+        let (w, cells, step) = (self.w, self.w * self.h, self.time);
+        let mut fields: Vec<(InvariantField, &[f32], bool)> = (0..self.params.channels)
+            .map(|c| (InvariantField::Matter(c), &self.a[c * cells..(c + 1) * cells], true))
+            .collect();
+        if let Some(e) = &self.energy {
+            fields.push((InvariantField::Energy, &e.field, true));
+        }
+        if let Some(d) = &self.detritus {
+            fields.push((InvariantField::Detritus, &d.field, true));
+        }
+        if let Some(sig) = &self.signal {
+            fields.push((InvariantField::Signal, &sig.field, false));
+        }
+        for (field, values, non_negative) in fields {
+            for (i, &value) in values.iter().enumerate() {
+                let (x, y) = (i % w, i / w);
+                if !value.is_finite() {
+                    return Err(InvariantViolation::NonFinite { field, x, y, step, value });
+                }
+                if non_negative && value < -NEGATIVE_SLACK {
+                    return Err(InvariantViolation::Negative { field, x, y, step, value });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the per-step validator, latching the first violation.
+    fn run_validation(&mut self) {
+        let Some(v) = self.validation.as_ref() else { return };
+        if v.violation.is_some() {
+            return;
+        }
+        let (tolerance, baseline) = (v.mass_tolerance, v.baseline);
+        let mut found = self.validate().err();
+        if found.is_none() {
+            if let Some(expected) = baseline {
+                let actual = self.conserved_mass();
+                let drift = (actual - expected).abs() / expected.abs().max(f64::MIN_POSITIVE);
+                // NaN drift (a non-finite total) counts as drift too.
+                if drift.is_nan() || drift > tolerance {
+                    let step = self.time;
+                    found = Some(InvariantViolation::MassDrift { step, expected, actual });
+                }
+            }
+        }
+        self.validation.as_mut().unwrap().violation = found;
+    }
+
+    /// Turn on **sparse stepping**. The world tracks which `16×16` tiles of
+    /// each channel hold matter above `epsilon`, and skips the kernel
+    /// convolution — the dominant cost — for tiles with no such matter within
+    /// the kernel's reach; their potential is taken as `0`. The gradient and
+    /// transport pass likewise skips tiles holding no matter at all, whatever
+    /// `epsilon`: there is nothing there to move, so that skip is exact.
+    ///
+    /// With `epsilon = 0.0` only exactly empty tiles are skipped, whose
+    /// potential the dense convolution sums to exactly `0` as well, so the run
+    /// is bitwise identical to the dense step. That saves work only where a
+    /// region holds no matter at all, e.g. around compact patches from
+    /// [`seed_random_patch`](Self::seed_random_patch). Smooth seeds (e.g.
+    /// [`seed_blob`](Self::seed_blob)) have tails that never quite reach zero,
+    /// so on such worlds exact mode convolves every tile and is no faster than
+    /// the dense step. The speedup comes from a small positive `epsilon`
+    /// (around `1e-4`), which ignores those tails at the cost of an error of
+    /// order `epsilon` in the potential.
+    pub fn enable_sparse(&mut self, epsilon: f32) {
+        self.sparse =
+            Some(Sparse { epsilon: epsilon.max(0.0), active: Vec::new(), occupied: Vec::new() });
+    }
+
+    /// Whether sparse stepping is on.
+    pub fn sparse_enabled(&self) -> bool {
+        self.sparse.is_some()
+    }
+
+    /// Fraction of (channel, tile) pairs the last sparse step convolved, or
+    /// `None` if sparse stepping is off — the work actually done relative to a
+    /// dense step.
+    pub fn active_tile_fraction(&self) -> Option<f32> {
+        let active = &self.sparse.as_ref()?.active;
+        if active.is_empty() {
+            return Some(1.0);
+        }
+        Some(active.iter().filter(|&&a| a).count() as f32 / active.len() as f32)
+    }
+
+    /// Recompute the sparse activity mask: a tile is active for a channel if
+    /// any of that channel's matter above `epsilon` lies within the kernel
+    /// reach of it. Also record which tiles hold any matter at all.
+    fn update_active_tiles(&mut self) {
+        let Some(Sparse { epsilon, mut active, mut occupied }) = self.sparse.take() else {
+            return;
+        };
+        let (w, h, cells) = (self.w, self.h, self.w * self.h);
+        let (tx, ty) = (w.div_ceil(SPARSE_TILE), h.div_ceil(SPARSE_TILE));
+        let tiles = tx * ty;
+        active.clear();
+        active.resize(self.params.channels * tiles, false);
+        occupied.clear();
+        // Cell span `[start, start + len)` of tile `t` along an axis of size `n`.
+        let span = |t: usize, n: usize| (t * SPARSE_TILE, SPARSE_TILE.min(n - t * SPARSE_TILE));
+        // Whether two spans intersect on a circle of size `n`.
+        let meets = |(a, la): (usize, usize), (b, lb): (usize, usize), n: usize| {
+            la >= n || lb >= n || (b + n - a) % n < la || (a + n - b) % n < lb
+        };
+        let r = self.reach;
+        for c in 0..self.params.channels {
+            let field = &self.a[c * cells..(c + 1) * cells];
+            // Per tile: the largest |value|, and whether any matter is there.
+            let peaks: Vec<(f32, bool)> = (0..tiles)
+                .map(|t| {
+                    let ((x0, lx), (y0, ly)) = (span(t % tx, w), span(t / tx, h));
+                    (y0..y0 + ly)
+                        .flat_map(|y| &field[y * w + x0..y * w + x0 + lx])
+                        .fold((0.0f32, false), |(p, any), &v| (p.max(v.abs()), any || v > 0.0))
+                })
+                .collect();
+            occupied.extend(peaks.iter().map(|&(_, any)| any));
+            let sources: Vec<(usize, usize)> = (0..tiles)
+                .filter(|&t| peaks[t].0 > epsilon)
+                .map(|t| (t % tx, t / tx))
+                .collect();
+            for t in 0..tiles {
+                let ((x0, lx), (y0, ly)) = (span(t % tx, w), span(t / tx, h));
+                // The tile dilated by the kernel reach, wrapped onto the torus.
+                let dx = ((x0 + w * (r / w + 1) - r) % w, lx + 2 * r);
+                let dy = ((y0 + h * (r / h + 1) - r) % h, ly + 2 * r);
+                active[c * tiles + t] = sources
+                    .iter()
+                    .any(|&(i, j)| meets(dx, span(i, w), w) && meets(dy, span(j, h), h));
+            }
+        }
+        self.sparse = Some(Sparse { epsilon, active, occupied });
+    }
+
+    /// Turn on **diagnostics**: from the next step on, the world records the
+    /// intermediate fields of each step — potential, energy gate, flow vectors,
+    /// `α` and the clamp mask — for the accessors below. Costs a few extra
+    /// field writes per step; off by default. The affinity `U` is always kept.
+    pub fn enable_diagnostics(&mut self) {
+        let (cells, channels) = (self.w * self.h, self.params.channels);
+        self.diagnostics = Some(Diagnostics {
+            potential: vec![0.0; cells * channels],
+            gate: vec![1.0; cells],
+            flow_x: vec![0.0; cells * channels],
+            flow_y: vec![0.0; cells * channels],
+            alpha: vec![0.0; cells],
+            clamped: vec![false; cells * channels],
+            mass: vec![0.0; cells * channels],
+        });
+    }
+
+    /// Whether diagnostics are being recorded.
+    pub fn diagnostics_enabled(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// Affinity `U` of channel `c` from the last step (after growth mapping and
+    /// the energy gate). All zero before the first step.
+    pub fn affinity(&self, c: usize) -> &[f32] {
+        let cells = self.w * self.h;
+        &self.potential[c * cells..(c + 1) * cells]
+    }
+
+    /// Neighborhood potential of channel `c` from the last step, or `None`
+    /// without diagnostics.
+    pub fn potential_field(&self, c: usize) -> Option<&[f32]> {
+        let cells = self.w * self.h;
+        self.diagnostics.as_ref().map(|d| &d.potential[c * cells..(c + 1) * cells])
+    }
+
+    /// Energy gate `g(E)` from the last step, or `None` without diagnostics.
+    pub fn gate_field(&self) -> Option<&[f32]> {
+        self.diagnostics.as_ref().map(|d| d.gate.as_slice())
+    }
+
+    /// Flow components `(F_x, F_y)` of channel `c` from the last step, before
+    /// the `max_flow` clamp, or `None` without diagnostics.
+    pub fn flow_field(&self, c: usize) -> Option<(&[f32], &[f32])> {
+        let cells = self.w * self.h;
+        self.diagnostics.as_ref().map(|d| {
+            let r = c * cells..(c + 1) * cells;
+            (&d.flow_x[r.clone()], &d.flow_y[r])
+        })
+    }
+
+    /// Mass-regulation ramp `α(x)` from the last step, or `None` without
+    /// diagnostics.
+    pub fn alpha_field(&self) -> Option<&[f32]> {
+        self.diagnostics.as_ref().map(|d| d.alpha.as_slice())
+    }
+
+    /// Cells of channel `c` whose displacement `max_flow` clamped in the last
+    /// step, or `None` without diagnostics.
+    pub fn clamp_mask(&self, c: usize) -> Option<&[bool]> {
+        let cells = self.w * self.h;
+        self.diagnostics.as_ref().map(|d| &d.clamped[c * cells..(c + 1) * cells])
+    }
+
+    /// Matter of channel `c` as it stood when the last step's flow was computed
+    /// (before transport), or `None` without diagnostics — the natural weight
+    /// for averaging the flow fields.
+    pub fn transported_mass(&self, c: usize) -> Option<&[f32]> {
+        let cells = self.w * self.h;
+        self.diagnostics.as_ref().map(|d| &d.mass[c * cells..(c + 1) * cells])
+    }
+
+    /// Enable **thermal noise** (F8) with the given parameters. See
+    /// [`NoiseParams`].
+    pub fn enable_noise(&mut self, params: NoiseParams) {
+        self.noise = Some(params);
+    }
+
+    /// Whether thermal noise is active.
+    pub fn noise_enabled(&self) -> bool {
+        self.noise.is_some()
+    }
+
+    /// Reseed the noise stream — for stochastic replicates of one initial
+    /// condition. No-op if noise is disabled.
+    pub fn set_noise_seed(&mut self, seed: u64) {
+        if let Some(n) = self.noise.as_mut() {
+            n.seed = seed;
+        }
+    }
+
+    /// Enable the **signal channel** (F4) with the given parameters. The signal
+    /// field starts empty. With the genome on, its secretion-rate field is reset
+    /// to the new global `production` (response strengths are kept).
+    pub fn enable_signal(&mut self, params: SignalParams) {
+        let cells = self.w * self.h;
+        if let Some(g) = self.genome.as_mut() {
+            g.emit.iter_mut().for_each(|e| *e = params.production);
+        }
+        self.signal = Some(Signal { params, field: vec![0.0; cells], scratch: vec![0.0; cells] });
+    }
+
+    /// Whether the signal channel is active.
+    pub fn signal_enabled(&self) -> bool {
+        self.signal.is_some()
+    }
+
+    /// Read-only view of the signal field, or `None` if the channel is disabled.
+    pub fn signal_field(&self) -> Option<&[f32]> {
+        self.signal.as_ref().map(|s| s.field.as_slice())
+    }
+
+    /// Total signal currently in the world, or `None` if the channel is disabled.
+    pub fn total_signal(&self) -> Option<f64> {
+        self.signal
+            .as_ref()
+            .map(|s| s.field.iter().map(|&v| v as f64).sum())
+    }
+
     /// Enable **localized parameters** (M-γ-1): the growth genome `(μ, σ)` becomes
-    /// a per-cell field that advects with the mass. The field is initialized to
-    /// the world's global `growth_mu`/`growth_sigma`; paint distinct species with
+    /// a per-cell field that advects with the mass, along with the kernel
+    /// orientation and chemotactic coupling. The fields are initialized to the
+    /// world's global values; paint distinct species with
     /// [`paint_genome`](Self::paint_genome) or [`seed_species`](Self::seed_species).
     ///
     /// # Panics
@@ -418,8 +1547,17 @@ impl World {
         self.genome = Some(Genome {
             mu: vec![self.params.growth_mu; cells],
             sigma: vec![self.params.growth_sigma; cells],
+            orient: vec![self.params.shape.orientation; cells],
+            beta: vec![self.energy.as_ref().map_or(0.0, |e| e.params.chemotaxis); cells],
+            emit: vec![self.signal.as_ref().map_or(0.0, |s| s.params.production); cells],
+            sense: vec![1.0; cells],
             mu_acc: vec![0.0; cells],
             sigma_acc: vec![0.0; cells],
+            beta_acc: vec![0.0; cells],
+            emit_acc: vec![0.0; cells],
+            sense_acc: vec![0.0; cells],
+            orient_cos_acc: vec![0.0; cells],
+            orient_sin_acc: vec![0.0; cells],
         });
     }
 
@@ -433,9 +1571,101 @@ impl World {
         self.genome.as_ref().map(|g| g.mu.as_slice())
     }
 
-    /// Read-only view of the per-cell growth-width field `σ(x)`, or `None`.
-    pub fn sigma_field(&self) -> Option<&[f32]> {
-        self.genome.as_ref().map(|g| g.sigma.as_slice())
+    /// Read-only view of the per-cell growth-width field `σ(x)`, or `None`.
+    pub fn sigma_field(&self) -> Option<&[f32]> {
+        self.genome.as_ref().map(|g| g.sigma.as_slice())
+    }
+
+    /// Read-only view of the per-cell kernel orientation field `φ(x)`, or `None`.
+    /// Only affects the dynamics when the kernel has an angular harmonic.
+    pub fn orientation_field(&self) -> Option<&[f32]> {
+        self.genome.as_ref().map(|g| g.orient.as_slice())
+    }
+
+    /// Everything the localized genome holds at cell `(x, y)`, or `None` if the
+    /// genome is disabled.
+    pub fn genes(&self, x: usize, y: usize) -> Option<Genes> {
+        let g = self.genome.as_ref()?;
+        let i = y * self.w + x;
+        Some(Genes {
+            mu: g.mu[i],
+            sigma: g.sigma[i],
+            orientation: g.orient[i],
+            beta: g.beta[i],
+            emit: g.emit[i],
+            sense: g.sense[i],
+        })
+    }
+
+    /// Hard-set the whole localized genome at cell `(x, y)`. No-op if the
+    /// genome is disabled.
+    pub fn set_genes(&mut self, x: usize, y: usize, genes: Genes) {
+        let i = y * self.w + x;
+        if let Some(g) = self.genome.as_mut() {
+            g.mu[i] = genes.mu;
+            g.sigma[i] = genes.sigma;
+            g.orient[i] = genes.orientation;
+            g.beta[i] = genes.beta;
+            g.emit[i] = genes.emit;
+            g.sense[i] = genes.sense;
+        }
+    }
+
+    /// Hard-set the local kernel orientation `φ` (radians) for every cell within
+    /// `radius` of `(cx, cy)`. No-op if the genome is disabled.
+    pub fn paint_orientation(&mut self, cx: f32, cy: f32, radius: f32, angle: f32) {
+        let (w, h) = (self.w, self.h);
+        let Some(g) = self.genome.as_mut() else { return };
+        for y in 0..h {
+            for x in 0..w {
+                let dx = torus_delta(x as f32, cx, w as f32);
+                let dy = torus_delta(y as f32, cy, h as f32);
+                if dx * dx + dy * dy <= radius * radius {
+                    g.orient[y * w + x] = angle;
+                }
+            }
+        }
+    }
+
+    /// Read-only view of the per-cell chemotactic coupling field `β(x)`, or `None`.
+    pub fn chemotaxis_field(&self) -> Option<&[f32]> {
+        self.genome.as_ref().map(|g| g.beta.as_slice())
+    }
+
+    /// Hard-set the local chemotactic coupling `β` for every cell within `radius`
+    /// of `(cx, cy)`. Only matters with the energy economy on. No-op if the genome
+    /// is disabled.
+    pub fn paint_chemotaxis(&mut self, cx: f32, cy: f32, radius: f32, beta: f32) {
+        let (w, h) = (self.w, self.h);
+        let Some(g) = self.genome.as_mut() else { return };
+        for y in 0..h {
+            for x in 0..w {
+                let dx = torus_delta(x as f32, cx, w as f32);
+                let dy = torus_delta(y as f32, cy, h as f32);
+                if dx * dx + dy * dy <= radius * radius {
+                    g.beta[y * w + x] = beta;
+                }
+            }
+        }
+    }
+
+    /// Hard-set the local signal genes — secretion rate `emit` and response
+    /// strength `sense` — for every cell within `radius` of `(cx, cy)`. No-op if
+    /// the genome is disabled.
+    pub fn paint_signal_genes(&mut self, cx: f32, cy: f32, radius: f32, emit: f32, sense: f32) {
+        let (w, h) = (self.w, self.h);
+        let Some(g) = self.genome.as_mut() else { return };
+        for y in 0..h {
+            for x in 0..w {
+                let dx = torus_delta(x as f32, cx, w as f32);
+                let dy = torus_delta(y as f32, cy, h as f32);
+                if dx * dx + dy * dy <= radius * radius {
+                    let idx = y * w + x;
+                    g.emit[idx] = emit;
+                    g.sense[idx] = sense;
+                }
+            }
+        }
     }
 
     /// Paint a species' genome: hard-set the local growth `(μ, σ)` for every cell
@@ -505,6 +1735,12 @@ impl World {
         &mut self.a[c * cells..(c + 1) * cells]
     }
 
+    /// Mass held by one channel, summed over cells. Trophic links move mass
+    /// between channels, so these vary while their sum stays fixed.
+    pub fn channel_mass(&self, c: usize) -> f64 {
+        self.channel(c).iter().map(|&v| v as f64).sum()
+    }
+
     /// Total mass summed over every channel and cell — the conserved quantity.
     pub fn total_mass(&self) -> f64 {
         self.a.iter().map(|&v| v as f64).sum()
@@ -635,59 +1871,198 @@ impl World {
 
     /// Advance the world by one timestep. Total mass is invariant.
     pub fn step(&mut self) {
+        self.begin_step();
         let (w, h, cells) = (self.w, self.h, self.w * self.h);
         let channels = self.params.channels;
 
         // 1 & 2. Potential (kernel * A) → affinity U_i via growth mapping.
-        //        Also accumulate total mass A_Σ. If the energy economy is on, the
-        //        positive part of affinity is gated by local energy g(E)=E/(E+K):
-        //        starved matter loses the pull that concentrates it into structure.
-        let (mu, sigma) = (self.params.growth_mu, self.params.growth_sigma);
-        // Move the genome out so its per-cell fields can be read here (growth) and
-        // its advection accumulators written below (transport) without borrowing
-        // all of `self`. Restored before the function returns.
-        let mut genome = self.genome.take();
-        for v in self.total.iter_mut() {
-            *v = 0.0;
-        }
-        for c in 0..channels {
-            let base = c * cells;
-            for y in 0..h {
-                for x in 0..w {
-                    let mut acc = 0.0f32;
-                    for tap in &self.kernel {
-                        let sx = wrap(x as i32 + tap.dx, w);
-                        let sy = wrap(y as i32 + tap.dy, h);
-                        acc += self.a[base + sy * w + sx] * tap.w;
+        //        Also accumulate total mass A_Σ (see `store_affinity`).
+        // Move the genome out so its per-cell fields can be read while the
+        // affinity is stored without borrowing all of `self`.
+        let genome = self.genome.take();
+        // Sparse stepping: tiles with no matter in reach convolve to exactly 0.
+        self.update_active_tiles();
+        // Localized orientation: sense through the harmonic basis at each cell's
+        // own φ. Otherwise the pre-oriented global kernel is exact and cheaper.
+        let oriented = self.basis.is_some() && genome.is_some();
+        if !oriented && self.sparse.is_none() {
+            // The common case: global kernel, every cell, no per-cell checks.
+            for c in 0..channels {
+                let base = c * cells;
+                for y in 0..h {
+                    for x in 0..w {
+                        let acc = self.convolve(base, x, y);
+                        self.store_affinity(genome.as_ref(), base, y * w + x, acc);
                     }
-                    let idx = y * w + x;
-                    // Localized growth (M-γ-1): matter here maps through its own
-                    // genome's (μ, σ) if the genome is on, else the global rule.
-                    let (gmu, gsig) = match &genome {
-                        Some(g) => (g.mu[idx], g.sigma[idx]),
-                        None => (mu, sigma),
-                    };
-                    let mut u = growth(acc, gmu, gsig);
-                    if let Some(e) = &self.energy {
-                        // Throttle the organizing affinity by local energy. Its
-                        // gradient is what drives transport, so scaling it down
-                        // starves matter of the flow that concentrates it — and
-                        // the anti-crowding term (always on) then disperses what
-                        // energy can no longer hold together.
-                        let ev = e.field[idx];
-                        u *= ev / (ev + e.params.gate_half);
+                }
+            }
+        } else {
+            let sparse = self.sparse.take();
+            let (tiles_x, tiles) =
+                (w.div_ceil(SPARSE_TILE), w.div_ceil(SPARSE_TILE) * h.div_ceil(SPARSE_TILE));
+            for c in 0..channels {
+                let base = c * cells;
+                for y in 0..h {
+                    for x in 0..w {
+                        let idx = y * w + x;
+                        let tile = c * tiles + (y / SPARSE_TILE) * tiles_x + x / SPARSE_TILE;
+                        let acc = match (&sparse, &genome) {
+                            (Some(s), _) if !s.active[tile] => 0.0,
+                            (_, Some(g)) if oriented => {
+                                self.convolve_oriented(base, x, y, g.orient[idx])
+                            }
+                            _ => self.convolve(base, x, y),
+                        };
+                        self.store_affinity(genome.as_ref(), base, idx, acc);
                     }
-                    self.potential[base + idx] = u;
-                    self.total[idx] += self.a[base + idx];
                 }
             }
+            self.sparse = sparse;
+        }
+        self.genome = genome;
+        self.finish_step();
+    }
+
+    /// Potential of the channel starting at `base` at `(x, y)`: the global
+    /// kernel's weighted sum.
+    #[inline]
+    fn convolve(&self, base: usize, x: usize, y: usize) -> f32 {
+        let (w, h) = (self.w, self.h);
+        let mut acc = 0.0f32;
+        for tap in &self.kernel {
+            let sx = wrap(x as i32 + tap.dx, w);
+            let sy = wrap(y as i32 + tap.dy, h);
+            acc += self.a[base + sy * w + sx] * tap.w;
+        }
+        acc
+    }
+
+    /// [`convolve`](Self::convolve) through the harmonic basis, with the kernel
+    /// turned to the cell's own orientation `orient`.
+    #[inline]
+    fn convolve_oriented(&self, base: usize, x: usize, y: usize, orient: f32) -> f32 {
+        let (w, h) = (self.w, self.h);
+        let b = self.basis.as_ref().expect("oriented convolution needs the basis");
+        let harmonic = self.params.shape.harmonic as f32;
+        let amplitude = self.params.shape.amplitude.clamp(0.0, 1.0);
+        let (mut p, mut pc, mut ps) = (0.0f32, 0.0f32, 0.0f32);
+        for tap in &b.taps {
+            let sx = wrap(x as i32 + tap.dx, w);
+            let sy = wrap(y as i32 + tap.dy, h);
+            let v = self.a[base + sy * w + sx];
+            p += v * tap.w;
+            pc += v * tap.wc;
+            ps += v * tap.ws;
+        }
+        let (sin, cos) = (harmonic * orient).sin_cos();
+        let norm = 1.0 + amplitude * (cos * b.sum_c + sin * b.sum_s);
+        (p + amplitude * (cos * pc + sin * ps)) / norm
+    }
+
+    /// Panic unless the world's convolution is its global kernel, as
+    /// [`WorldBatch`] computes it.
+    fn assert_batchable(&self) {
+        assert!(
+            self.basis.is_none() || self.genome.is_none(),
+            "a world with per-cell orientation cannot be batched"
+        );
+    }
+
+    /// Open a step: take the validator's mass baseline if this is its first
+    /// step, apply schedules (F7) and clear `A_Σ` for the potential pass.
+    fn begin_step(&mut self) {
+        // The validator's mass baseline is the state before its first step.
+        if self.validation.as_ref().is_some_and(|v| v.baseline.is_none()) {
+            let mass = self.conserved_mass();
+            if let Some(v) = self.validation.as_mut() {
+                v.baseline = Some(mass);
+            }
+        }
+        // 0. Schedules (F7), if any: set this step's global parameters.
+        self.apply_schedules();
+        for v in self.total.iter_mut() {
+            *v = 0.0;
+        }
+    }
+
+    /// Map the potential `acc` at cell `idx` of the channel starting at `base`
+    /// through the growth function into affinity, and add the cell's matter to
+    /// `A_Σ`. If the energy economy is on, the positive part of affinity is
+    /// gated by local energy g(E)=E/(E+K): starved matter loses the pull that
+    /// concentrates it into structure. `genome` is the world's own, moved out.
+    #[inline]
+    fn store_affinity(&mut self, genome: Option<&Genome>, base: usize, idx: usize, acc: f32) {
+        // Localized growth (M-γ-1): matter here maps through its own
+        // genome's (μ, σ) if the genome is on, else the global rule.
+        let (mut gmu, mut gsig) = match genome {
+            Some(g) => (g.mu[idx], g.sigma[idx]),
+            None => (self.params.growth_mu, self.params.growth_sigma),
+        };
+        // Environment: location shifts the growth window.
+        if let Some(env) = &self.environment {
+            let t = env.field[idx];
+            gmu += env.params.mu_shift * t;
+            gsig = (gsig + env.params.sigma_shift * t).max(1e-4);
+        }
+        // Signal response: sensed signal shifts the growth center.
+        if let Some(sig) = &self.signal {
+            let sense = genome.map_or(1.0, |g| g.sense[idx]);
+            gmu += sense * sig.params.growth_shift * sig.field[idx];
+        }
+        let mut u = growth(acc, gmu, gsig);
+        let mut gate = 1.0;
+        if let Some(e) = &self.energy {
+            // Throttle the organizing affinity by local energy. Its
+            // gradient is what drives transport, so scaling it down
+            // starves matter of the flow that concentrates it — and
+            // the anti-crowding term (always on) then disperses what
+            // energy can no longer hold together.
+            let ev = e.field[idx];
+            gate = ev / (ev + e.params.gate_half);
+            u *= gate;
+        }
+        if let Some(d) = self.diagnostics.as_mut() {
+            d.potential[base + idx] = acc;
+            d.gate[idx] = gate;
         }
+        self.potential[base + idx] = u;
+        self.total[idx] += self.a[base + idx];
+    }
+
+    /// Close a step once the affinity is stored: flow, transport, genome
+    /// advection, then every enabled layer's update, the clock and validation.
+    fn finish_step(&mut self) {
+        let (w, h, cells) = (self.w, self.h, self.w * self.h);
+        let channels = self.params.channels;
+        // The genome's advection accumulators are written below without
+        // borrowing all of `self`; restored after the transport pass.
+        let mut genome = self.genome.take();
 
-        // 3 & 4. Per channel: flow from ∇U_i and ∇A_Σ, then transport.
+        // 3 & 4. Per channel: flow from ∇U_i, ∇A_Σ (and ∇E), then transport.
         let dt = self.params.dt;
         let theta = self.params.theta_a;
         let n = self.params.alpha_n;
+        if let Some(d) = self.diagnostics.as_mut() {
+            for (a, &t) in d.alpha.iter_mut().zip(&self.total) {
+                *a = ((t / theta).powf(n)).clamp(0.0, 1.0);
+            }
+            d.flow_x.iter_mut().for_each(|v| *v = 0.0);
+            d.flow_y.iter_mut().for_each(|v| *v = 0.0);
+            d.clamped.iter_mut().for_each(|v| *v = false);
+            d.mass.copy_from_slice(&self.a);
+        }
         let max_flow = self.params.max_flow;
+        // Chemotaxis reads the energy field as it stood before this step's update.
+        let chemotaxis = self
+            .energy
+            .as_ref()
+            .map(|e| (e.field.as_slice(), e.params.chemotaxis));
+        // Flow noise: the seed of this step's draws and their amplitude.
+        let flow_noise = self
+            .noise
+            .as_ref()
+            .filter(|n| n.flow > 0.0)
+            .map(|n| (noise_key(n.seed, self.time as u64), n.flow));
         // Zero the genome advection accumulators for this step.
         if let Some(g) = genome.as_mut() {
             for v in g.mu_acc.iter_mut() {
@@ -696,14 +2071,51 @@ impl World {
             for v in g.sigma_acc.iter_mut() {
                 *v = 0.0;
             }
+            for v in g.beta_acc.iter_mut() {
+                *v = 0.0;
+            }
+            for v in g.emit_acc.iter_mut() {
+                *v = 0.0;
+            }
+            for v in g.sense_acc.iter_mut() {
+                *v = 0.0;
+            }
+            for v in g.orient_cos_acc.iter_mut() {
+                *v = 0.0;
+            }
+            for v in g.orient_sin_acc.iter_mut() {
+                *v = 0.0;
+            }
         }
+        // Sparse stepping: a tile without matter has nothing to move, so each
+        // row visits only the spans of occupied tiles, in dense raster order.
+        let (tiles_x, tiles) =
+            (w.div_ceil(SPARSE_TILE), w.div_ceil(SPARSE_TILE) * h.div_ceil(SPARSE_TILE));
+        let occupied = self
+            .sparse
+            .as_mut()
+            .map(|s| std::mem::take(&mut s.occupied))
+            .filter(|o| o.len() == channels * tiles);
+        // Cell spans `[x0, x1)` of the row to visit.
+        let mut spans = vec![(0, w)];
         for c in 0..channels {
             let base = c * cells;
             for v in self.scratch.iter_mut() {
                 *v = 0.0;
             }
             for y in 0..h {
-                for x in 0..w {
+                if let Some(occupied) = occupied.as_ref().filter(|_| y % SPARSE_TILE == 0) {
+                    let row = &occupied[c * tiles + (y / SPARSE_TILE) * tiles_x..][..tiles_x];
+                    spans.clear();
+                    for (t, _) in row.iter().enumerate().filter(|(_, &o)| o) {
+                        let (x0, x1) = (t * SPARSE_TILE, ((t + 1) * SPARSE_TILE).min(w));
+                        match spans.last_mut() {
+                            Some(last) if last.1 == x0 => last.1 = x1,
+                            _ => spans.push((x0, x1)),
+                        }
+                    }
+                }
+                for x in spans.iter().flat_map(|&(x0, x1)| x0..x1) {
                     let src = y * w + x;
                     let m = self.a[base + src];
                     if m <= 0.0 {
@@ -715,9 +2127,39 @@ impl World {
                     // Anti-crowding ramp: engage mass regulation as A_Σ → θ_A.
                     let a_sigma = self.total[src];
                     let alpha = ((a_sigma / theta).powf(n)).clamp(0.0, 1.0);
-                    let fx = (1.0 - alpha) * gux - alpha * gax;
-                    let fy = (1.0 - alpha) * guy - alpha * gay;
-                    // Displacement in cells, clamped for advection fidelity.
+                    let mut fx = (1.0 - alpha) * gux - alpha * gax;
+                    let mut fy = (1.0 - alpha) * guy - alpha * gay;
+                    // Signal taxis: follow (or flee) the signal gradient.
+                    if let Some(sig) = &self.signal {
+                        let sense = genome.as_ref().map_or(1.0, |g| g.sense[src]);
+                        let k = sense * sig.params.taxis;
+                        if k != 0.0 {
+                            let (gsx, gsy) = sobel(&sig.field, w, h, x, y);
+                            fx += k * gsx;
+                            fy += k * gsy;
+                        }
+                    }
+                    // Chemotaxis: drift up (β > 0) or down the energy gradient.
+                    if let Some((ef, global_beta)) = chemotaxis {
+                        let beta = genome.as_ref().map_or(global_beta, |g| g.beta[src]);
+                        if beta != 0.0 {
+                            let (gex, gey) = sobel(ef, w, h, x, y);
+                            fx += beta * gex;
+                            fy += beta * gey;
+                        }
+                    }
+                    // Thermal kick, keyed by (step, channel, cell).
+                    if let Some((key, amp)) = flow_noise {
+                        let (nx, ny) = gaussian_pair(noise_key(key, (base + src) as u64));
+                        fx += amp * nx;
+                        fy += amp * ny;
+                    }
+                    // Displacement in cells, clamped for advection fidelity. The
+                    // environment, if any, speeds up or freezes local time.
+                    let dt = match &self.environment {
+                        Some(env) => dt * (1.0 + env.params.dt_scale * env.field[src]).max(0.0),
+                        None => dt,
+                    };
                     let (mut dx, mut dy) = (fx * dt, fy * dt);
                     let mag = (dx * dx + dy * dy).sqrt();
                     if mag > max_flow {
@@ -725,6 +2167,11 @@ impl World {
                         dx *= s;
                         dy *= s;
                     }
+                    if let Some(d) = self.diagnostics.as_mut() {
+                        d.flow_x[base + src] = fx;
+                        d.flow_y[base + src] = fy;
+                        d.clamped[base + src] = mag > max_flow;
+                    }
                     // Bilinear (reintegration-tracking) scatter of mass `m` onto
                     // the unit box centered at (x+dx, y+dy). Weights sum to 1.
                     let tx = x as f32 + dx;
@@ -753,20 +2200,24 @@ impl World {
                     // (μ, σ) to the same four cells, weighted by the moved mass.
                     // Resolved into a mass-weighted average after the channel loop.
                     if let Some(g) = genome.as_mut() {
-                        let (gmu, gsig) = (g.mu[src], g.sigma[src]);
-                        g.mu_acc[d00] += m00 * gmu;
-                        g.mu_acc[d01] += m01 * gmu;
-                        g.mu_acc[d10] += m10 * gmu;
-                        g.mu_acc[d11] += m11 * gmu;
-                        g.sigma_acc[d00] += m00 * gsig;
-                        g.sigma_acc[d01] += m01 * gsig;
-                        g.sigma_acc[d10] += m10 * gsig;
-                        g.sigma_acc[d11] += m11 * gsig;
+                        let (dst, moved) = ([d00, d01, d10, d11], [m00, m01, m10, m11]);
+                        let (osin, ocos) = g.orient[src].sin_cos();
+                        splat(&mut g.mu_acc, dst, moved, g.mu[src]);
+                        splat(&mut g.sigma_acc, dst, moved, g.sigma[src]);
+                        splat(&mut g.beta_acc, dst, moved, g.beta[src]);
+                        splat(&mut g.emit_acc, dst, moved, g.emit[src]);
+                        splat(&mut g.sense_acc, dst, moved, g.sense[src]);
+                        splat(&mut g.orient_cos_acc, dst, moved, ocos);
+                        splat(&mut g.orient_sin_acc, dst, moved, osin);
                     }
                 }
             }
             self.a[base..base + cells].copy_from_slice(&self.scratch);
         }
+        if let (Some(sparse), Some(mut occupied)) = (self.sparse.as_mut(), occupied) {
+            occupied.clear();
+            sparse.occupied = occupied;
+        }
 
         // Resolve the advected genome: each cell's new (μ, σ) is the mass-weighted
         // average of what arrived. `self.scratch` holds channel 0's post-transport
@@ -778,11 +2229,32 @@ impl World {
                 if m > 1e-9 {
                     g.mu[i] = g.mu_acc[i] / m;
                     g.sigma[i] = g.sigma_acc[i] / m;
+                    g.beta[i] = g.beta_acc[i] / m;
+                    g.emit[i] = g.emit_acc[i] / m;
+                    g.sense[i] = g.sense_acc[i] / m;
+                    // Opposing orientations cancel to a null vector; keep the
+                    // prior angle rather than snapping to an arbitrary one.
+                    let (oc, os) = (g.orient_cos_acc[i], g.orient_sin_acc[i]);
+                    if oc * oc + os * os > 1e-12 * m * m {
+                        g.orient[i] = os.atan2(oc);
+                    }
                 }
             }
         }
         self.genome = genome;
 
+        // Signal channel (F4), if enabled: secrete from post-transport mass,
+        // then diffuse and decay. Read by growth and flow on the next step.
+        self.update_signal();
+
+        // Exchange noise (F8), if enabled: random mass-conserving trades
+        // between neighbors.
+        self.exchange_noise();
+
+        // Trophic coupling (F5), if any: convert prey to predator in place. A_Σ
+        // is unchanged, so the energy update below still sees the true ΔA.
+        self.update_trophic();
+
         // 5. Energy economy (M-γ-2), if enabled: spend on growth + maintenance,
         //    inject from sources, diffuse. `self.total` still holds pre-transport
         //    A_Σ, so ΔA is recoverable against the just-updated matter.
@@ -792,6 +2264,36 @@ impl World {
         //    and decompose detritus back into the live channel + energy. Runs after
         //    the energy update so death reads this step's post-injection energy.
         self.update_detritus();
+
+        // 7. Environment drift (F6), if any: move the pattern for the next step.
+        self.update_environment();
+        self.time += 1;
+
+        // Invariant checks, if enabled.
+        self.run_validation();
+    }
+
+    /// Translate the environment pattern by its drift: `T` is resampled from the
+    /// undrifted base at the accumulated offset (bilinear, toroidal), so repeated
+    /// sub-cell drift does not blur the pattern. No-op without drift.
+    fn update_environment(&mut self) {
+        let (w, h) = (self.w, self.h);
+        let Some(env) = self.environment.as_mut() else { return };
+        let (vx, vy) = env.params.drift;
+        if vx == 0.0 && vy == 0.0 {
+            return;
+        }
+        env.offset = (
+            (env.offset.0 + vx).rem_euclid(w as f32),
+            (env.offset.1 + vy).rem_euclid(h as f32),
+        );
+        for y in 0..h {
+            for x in 0..w {
+                let sx = x as f32 - env.offset.0;
+                let sy = y as f32 - env.offset.1;
+                env.field[y * w + x] = sample_bilinear(&env.base, w, h, sx, sy);
+            }
+        }
     }
 
     /// Update the energy field one step: consumption (`ΔA > 0`), maintenance
@@ -811,7 +2313,11 @@ impl World {
             let energy = self.energy.as_mut().unwrap();
             let (consume, maintain, cap) =
                 (energy.params.consume, energy.params.maintain, energy.params.capacity);
+            let env = self.environment.as_ref();
             for i in 0..cells {
+                let maintain = env.map_or(maintain, |e| {
+                    maintain * (1.0 + e.params.maintain_scale * e.field[i]).max(0.0)
+                });
                 let mut a_new = 0.0f32;
                 for c in 0..channels {
                     a_new += self.a[c * cells + i];
@@ -820,7 +2326,7 @@ impl World {
                 let mut e = energy.field[i];
                 e -= consume * delta.max(0.0); // building structure costs energy
                 e -= maintain * a_new; // upkeep costs energy
-                e += energy.source[i]; // renewable injection
+                e += energy.source_scale * energy.source[i]; // renewable injection
                 energy.field[i] = e.clamp(0.0, cap);
             }
         }
@@ -848,6 +2354,98 @@ impl World {
         }
     }
 
+    /// Trade a random amount of matter across every 4-neighbor pair, per
+    /// channel. Trades are sized from the pre-exchange state and capped at a
+    /// quarter of the poorer cell, so no cell can go negative and total mass is
+    /// conserved exactly. No-op unless exchange noise is enabled.
+    fn exchange_noise(&mut self) {
+        let Some(noise) = self.noise.as_ref().filter(|n| n.exchange > 0.0) else { return };
+        let (w, h, cells) = (self.w, self.h, self.w * self.h);
+        let strength = noise.exchange.clamp(0.0, 1.0) * 0.25;
+        // A different stream from the flow kick of the same step.
+        let key = noise_key(noise.seed ^ 0x5851_F42D_4C95_7F2D, self.time as u64);
+        for c in 0..self.params.channels {
+            let base = c * cells;
+            self.scratch.copy_from_slice(&self.a[base..base + cells]);
+            let before = &self.scratch;
+            for y in 0..h {
+                for x in 0..w {
+                    let i = y * w + x;
+                    // Each cell owns the pairs to its right and below.
+                    let right = y * w + wrap(x as i32 + 1, w);
+                    let down = wrap(y as i32 + 1, h) * w + x;
+                    for (k, j) in [(0u64, right), (1, down)] {
+                        let cap = before[i].min(before[j]);
+                        if cap <= 0.0 {
+                            continue;
+                        }
+                        let draw = noise_key(key, ((base + i) as u64) << 1 | k);
+                        let xi = 2.0 * unit_float(draw) - 1.0;
+                        let q = xi * strength * cap;
+                        self.a[base + i] -= q;
+                        self.a[base + j] += q;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Apply every trophic link once: mass-action conversion of prey into
+    /// predator within each cell, releasing energy if the economy is on. Only
+    /// moves mass between channels, so total mass is conserved exactly.
+    fn update_trophic(&mut self) {
+        let cells = self.w * self.h;
+        for link in &self.trophic {
+            let (pred, prey) = (link.predator * cells, link.prey * cells);
+            for i in 0..cells {
+                let eaten = (link.rate * self.a[pred + i] * self.a[prey + i]).min(self.a[prey + i]);
+                if eaten <= 0.0 {
+                    continue;
+                }
+                self.a[prey + i] -= eaten;
+                self.a[pred + i] += eaten;
+                if let Some(e) = self.energy.as_mut() {
+                    e.field[i] = (e.field[i] + link.energy_yield * eaten).min(e.params.capacity);
+                }
+            }
+        }
+    }
+
+    /// Update the signal field one step: secretion `∝ emit·A_Σ`, decay, then
+    /// diffusion. Mass is untouched. No-op if the channel is disabled.
+    fn update_signal(&mut self) {
+        let Some(sig) = self.signal.as_mut() else { return };
+        let (w, h, cells) = (self.w, self.h, self.w * self.h);
+        let channels = self.params.channels;
+        let keep = 1.0 - sig.params.decay.clamp(0.0, 1.0);
+        for i in 0..cells {
+            let mut a = 0.0f32;
+            for c in 0..channels {
+                a += self.a[c * cells + i];
+            }
+            let emit = self.genome.as_ref().map_or(sig.params.production, |g| g.emit[i]);
+            sig.field[i] = ((sig.field[i] + emit * a) * keep).max(0.0);
+        }
+        let d = sig.params.diffusion;
+        if d > 0.0 {
+            let f = &sig.field;
+            let out = &mut sig.scratch;
+            for y in 0..h {
+                let ym = wrap(y as i32 - 1, h);
+                let yp = wrap(y as i32 + 1, h);
+                for x in 0..w {
+                    let xm = wrap(x as i32 - 1, w);
+                    let xp = wrap(x as i32 + 1, w);
+                    let c = f[y * w + x];
+                    let lap = f[y * w + xm] + f[y * w + xp] + f[ym * w + x] + f[yp * w + x]
+                        - 4.0 * c;
+                    out[y * w + x] = (c + d * lap).max(0.0);
+                }
+            }
+            std::mem::swap(&mut sig.field, &mut sig.scratch);
+        }
+    }
+
     /// Update the detritus cycle one step (M-γ-3): starved live matter dies into
     /// detritus, detritus decomposes back into the live channel and releases energy.
     /// Matter only *moves* between the live channel and detritus, so
@@ -863,6 +2461,7 @@ impl World {
         let k = energy.params.gate_half;
         let cap = energy.params.capacity;
         let DetritusParams { death_rate, recycle_matter, recycle_energy } = det.params;
+        let (mut died, mut recycled) = (0.0f64, 0.0f64);
         for i in 0..cells {
             // Death: the same energy shortage that closes the growth gate now kills.
             // s = 1 − g(E) = K/(E+K) → 1 as E → 0, 0 when energy is plentiful.
@@ -877,10 +2476,378 @@ impl World {
             det.field[i] = pool - back;
             self.a[i] += back;
             energy.field[i] = (energy.field[i] + recycle_energy * back).min(cap);
+            died += dead as f64;
+            recycled += back as f64;
+        }
+        (det.died, det.recycled) = (died, recycled);
+        det.total_died += died;
+        det.total_recycled += recycled;
+    }
+}
+
+/// Several same-sized worlds stepped in lockstep — the CPU counterpart of
+/// batching many worlds on the GPU, for search throughput. The kernel
+/// convolution, which dominates a step, runs over the whole batch at once:
+/// matter is gathered into a structure-of-arrays layout (the `K` worlds'
+/// values for a cell are contiguous) and each kernel tap is applied to every
+/// world with one offset computation and a unit-stride inner loop the compiler
+/// can vectorize. Worlds may have different parameters: their kernels are
+/// merged into one tap list, weighted zero where a world has no such tap. The
+/// rest of the step (flow, transport, every optional layer) runs per world.
+///
+/// Each world stays bitwise identical to stepping it alone, as long as its
+/// matter stays finite; a world with sparse stepping on keeps its own tile
+/// mask, its skipped tiles taking potential `0` as alone. Worlds that sense
+/// through a per-cell orientation (a harmonic kernel with the genome on) need
+/// their own basis and cannot be batched.
+pub struct WorldBatch {
+    worlds: Vec<World>,
+    /// Union of every world's kernel offsets as `(dy, dx)`, in raster order.
+    taps: Vec<(i32, i32)>,
+    /// Tap-major weights: `weights[t * K + k]` is world `k`'s weight at tap `t`.
+    weights: Vec<f32>,
+    /// Each world's `kernel_builds` when the tap list was merged.
+    builds: Vec<usize>,
+    /// Matter in SoA layout: `mass[(c * cells + i) * K + k]`.
+    mass: Vec<f32>,
+}
+
+impl WorldBatch {
+    /// Batch `worlds`. Panics if they differ in size or channel count, or if
+    /// one senses through a per-cell orientation.
+    pub fn new(worlds: Vec<World>) -> Self {
+        if let Some(first) = worlds.first() {
+            for world in &worlds {
+                assert!(
+                    world.w == first.w && world.h == first.h,
+                    "batched worlds must share a size"
+                );
+                assert_eq!(
+                    world.params.channels, first.params.channels,
+                    "batched worlds must share a channel count"
+                );
+                world.assert_batchable();
+            }
+        }
+        let mut batch = WorldBatch {
+            worlds,
+            taps: Vec::new(),
+            weights: Vec::new(),
+            builds: Vec::new(),
+            mass: Vec::new(),
+        };
+        batch.merge_kernels();
+        batch
+    }
+
+    /// Number of worlds in the batch.
+    pub fn len(&self) -> usize {
+        self.worlds.len()
+    }
+
+    /// Whether the batch holds no worlds.
+    pub fn is_empty(&self) -> bool {
+        self.worlds.is_empty()
+    }
+
+    /// The batched worlds, in the order given.
+    pub fn worlds(&self) -> &[World] {
+        &self.worlds
+    }
+
+    /// Unbatch: the worlds, in the order given.
+    pub fn into_worlds(self) -> Vec<World> {
+        self.worlds
+    }
+
+    /// Advance every world by one timestep.
+    pub fn step(&mut self) {
+        let k = self.worlds.len();
+        if k == 0 {
+            return;
+        }
+        for world in &mut self.worlds {
+            world.begin_step();
+            world.update_active_tiles();
+        }
+        // A schedule may have reshaped a kernel.
+        if self.worlds.iter().zip(&self.builds).any(|(w, &b)| w.kernel_builds != b) {
+            for world in &self.worlds {
+                world.assert_batchable();
+            }
+            self.merge_kernels();
+        }
+        let (w, h) = (self.worlds[0].w, self.worlds[0].h);
+        let cells = w * h;
+        let channels = self.worlds[0].params.channels;
+
+        // Gather matter into the SoA layout.
+        self.mass.resize(channels * cells * k, 0.0);
+        for (j, world) in self.worlds.iter().enumerate() {
+            for (i, &v) in world.a.iter().enumerate() {
+                self.mass[i * k + j] = v;
+            }
+        }
+        // Potential for every world at once, then each world's own affinity.
+        let genomes: Vec<Option<Genome>> =
+            self.worlds.iter_mut().map(|world| world.genome.take()).collect();
+        let mut acc = vec![0.0f32; k];
+        let (tiles_x, tiles) =
+            (w.div_ceil(SPARSE_TILE), w.div_ceil(SPARSE_TILE) * h.div_ceil(SPARSE_TILE));
+        let sparse = self.worlds.iter().any(|world| world.sparse.is_some());
+        for c in 0..channels {
+            let base = c * cells;
+            for y in 0..h {
+                for x in 0..w {
+                    acc.fill(0.0);
+                    for (t, &(dy, dx)) in self.taps.iter().enumerate() {
+                        let src = base + wrap(y as i32 + dy, h) * w + wrap(x as i32 + dx, w);
+                        let mass = &self.mass[src * k..(src + 1) * k];
+                        let weights = &self.weights[t * k..(t + 1) * k];
+                        for ((a, &m), &wt) in acc.iter_mut().zip(mass).zip(weights) {
+                            *a += m * wt;
+                        }
+                    }
+                    let idx = y * w + x;
+                    // Sparse worlds: a skipped tile's potential is exactly 0.
+                    if sparse {
+                        let tile = c * tiles + (y / SPARSE_TILE) * tiles_x + x / SPARSE_TILE;
+                        for (a, world) in acc.iter_mut().zip(&self.worlds) {
+                            if world.sparse.as_ref().is_some_and(|s| !s.active[tile]) {
+                                *a = 0.0;
+                            }
+                        }
+                    }
+                    for ((world, genome), &a) in self.worlds.iter_mut().zip(&genomes).zip(&acc) {
+                        world.store_affinity(genome.as_ref(), base, idx, a);
+                    }
+                }
+            }
+        }
+        for (world, genome) in self.worlds.iter_mut().zip(genomes) {
+            world.genome = genome;
+            world.finish_step();
+        }
+    }
+
+    /// Merge every world's kernel into the shared tap list and weight matrix.
+    /// Kernels are built in raster order, so each world's taps keep their
+    /// order within the union and its sums are accumulated exactly as alone.
+    fn merge_kernels(&mut self) {
+        let k = self.worlds.len();
+        let mut taps: Vec<(i32, i32)> = self
+            .worlds
+            .iter()
+            .flat_map(|world| world.kernel.iter().map(|t| (t.dy, t.dx)))
+            .collect();
+        taps.sort_unstable();
+        taps.dedup();
+        let mut weights = vec![0.0f32; taps.len() * k];
+        for (j, world) in self.worlds.iter().enumerate() {
+            for tap in &world.kernel {
+                let t = taps.binary_search(&(tap.dy, tap.dx)).expect("tap in the union");
+                weights[t * k + j] = tap.w;
+            }
+        }
+        self.taps = taps;
+        self.weights = weights;
+        self.builds = self.worlds.iter().map(|world| world.kernel_builds).collect();
+    }
+}
+
+/// Scatter a gene value carried by moved mass onto the four reintegration
+/// targets, weighted by the mass landing on each.
+#[inline]
+fn splat(acc: &mut [f32], dst: [usize; 4], moved: [f32; 4], value: f32) {
+    for (&d, &m) in dst.iter().zip(&moved) {
+        acc[d] += m * value;
+    }
+}
+
+/// A linear environment gradient from `-1` to `1` along direction `angle`
+/// (radians; `0` = increasing with `x`), row-major `w×h`. The torus wraps, so
+/// there is a seam where the extremes meet.
+pub fn linear_gradient(w: usize, h: usize, angle: f32) -> Vec<f32> {
+    let (sin, cos) = angle.sin_cos();
+    let proj: Vec<f32> = (0..w * h)
+        .map(|i| (i % w) as f32 * cos + (i / w) as f32 * sin)
+        .collect();
+    let lo = proj.iter().copied().fold(f32::INFINITY, f32::min);
+    let hi = proj.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let span = (hi - lo).max(f32::EPSILON);
+    proj.into_iter().map(|p| 2.0 * (p - lo) / span - 1.0).collect()
+}
+
+/// An environment of `count` soft patches on a neutral (`0`) background: each
+/// patch is a Gaussian bump of standard deviation `radius` at a random center,
+/// with a random level in `[-1, 1]`. Overlaps add, clamped to `[-1, 1]`.
+pub fn patch_field<R: Rng>(w: usize, h: usize, count: usize, radius: f32, rng: &mut R) -> Vec<f32> {
+    let mut field = vec![0.0f32; w * h];
+    let inv = 1.0 / (2.0 * radius * radius);
+    for _ in 0..count {
+        let cx = rng.gen_range(0.0..w as f32);
+        let cy = rng.gen_range(0.0..h as f32);
+        let level = rng.gen_range(-1.0f32..=1.0);
+        for y in 0..h {
+            for x in 0..w {
+                let dx = torus_delta(x as f32, cx, w as f32);
+                let dy = torus_delta(y as f32, cy, h as f32);
+                field[y * w + x] += level * (-(dx * dx + dy * dy) * inv).exp();
+            }
+        }
+    }
+    field.iter_mut().for_each(|v| *v = v.clamp(-1.0, 1.0));
+    field
+}
+
+/// A Perlin-like environment: `octaves` of smoothly interpolated value noise,
+/// the first with features about `scale` cells across, each further octave at
+/// twice the frequency and half the amplitude. Tiles the torus seamlessly.
+/// Values in `[-1, 1]`.
+pub fn noise_field<R: Rng>(
+    w: usize,
+    h: usize,
+    scale: f32,
+    octaves: usize,
+    rng: &mut R,
+) -> Vec<f32> {
+    let mut field = vec![0.0f32; w * h];
+    let (mut cell, mut amp, mut norm) = (scale.max(1.0), 1.0f32, 0.0f32);
+    for _ in 0..octaves.max(1) {
+        // A lattice that divides the world, so the noise wraps cleanly.
+        let gx = ((w as f32 / cell).round() as usize).max(1);
+        let gy = ((h as f32 / cell).round() as usize).max(1);
+        let lattice: Vec<f32> = (0..gx * gy).map(|_| rng.gen_range(-1.0f32..=1.0)).collect();
+        for y in 0..h {
+            for x in 0..w {
+                let lx = x as f32 * gx as f32 / w as f32;
+                let ly = y as f32 * gy as f32 / h as f32;
+                field[y * w + x] += amp * sample_smooth(&lattice, gx, gy, lx, ly);
+            }
+        }
+        norm += amp;
+        amp *= 0.5;
+        cell *= 0.5;
+    }
+    field.iter_mut().for_each(|v| *v /= norm);
+    field
+}
+
+/// Furthest any tap of the kernel (or its harmonic basis) reaches from the
+/// center along either axis, in cells.
+fn kernel_reach(kernel: &[Tap], basis: Option<&HarmonicBasis>) -> usize {
+    let taps = kernel.iter().map(|t| (t.dx, t.dy));
+    let basis_taps = basis.into_iter().flat_map(|b| b.taps.iter().map(|t| (t.dx, t.dy)));
+    taps.chain(basis_taps)
+        .map(|(dx, dy)| dx.unsigned_abs().max(dy.unsigned_abs()) as usize)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Resample a toroidal `w×h` field to `nw×nh`, one axis at a time: a box
+/// average along an axis that shrinks (exactly mean-preserving) and linear
+/// interpolation between cell centers along one that grows.
+fn resample(field: &[f32], w: usize, h: usize, nw: usize, nh: usize) -> Vec<f32> {
+    let mut rows = Vec::with_capacity(nw * h);
+    for y in 0..h {
+        rows.extend(resample_line(&field[y * w..(y + 1) * w], nw));
+    }
+    let mut out = vec![0.0f32; nw * nh];
+    let mut column = vec![0.0f32; h];
+    for x in 0..nw {
+        for (y, v) in column.iter_mut().enumerate() {
+            *v = rows[y * nw + x];
         }
+        for (y, v) in resample_line(&column, nh).into_iter().enumerate() {
+            out[y * nw + x] = v;
+        }
+    }
+    out
+}
+
+/// Resample one periodic line of samples to `n` samples (see [`resample`]).
+fn resample_line(src: &[f32], n: usize) -> Vec<f32> {
+    let len = src.len();
+    let ratio = len as f32 / n as f32;
+    if n >= len {
+        (0..n)
+            .map(|i| {
+                let p = (i as f32 + 0.5) * ratio - 0.5;
+                let p0 = p.floor();
+                let f = p - p0;
+                let a = src[wrap(p0 as i32, len)];
+                let b = src[wrap(p0 as i32 + 1, len)];
+                a * (1.0 - f) + b * f
+            })
+            .collect()
+    } else {
+        (0..n)
+            .map(|i| {
+                let (lo, hi) = (i as f32 * ratio, (i + 1) as f32 * ratio);
+                let first = lo.floor() as usize;
+                let last = (hi.ceil() as usize).min(len);
+                let sum: f32 = src[first..last]
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &v)| {
+                        let j = (first + k) as f32;
+                        v * (hi.min(j + 1.0) - lo.max(j)).max(0.0)
+                    })
+                    .sum();
+                sum / ratio
+            })
+            .collect()
     }
 }
 
+/// Bilinear sample of a toroidal `w×h` field at fractional `(x, y)`.
+fn sample_bilinear(field: &[f32], w: usize, h: usize, x: f32, y: f32) -> f32 {
+    let (x0f, y0f) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0f, y - y0f);
+    let (x0, x1) = (wrap(x0f as i32, w), wrap(x0f as i32 + 1, w));
+    let (y0, y1) = (wrap(y0f as i32, h), wrap(y0f as i32 + 1, h));
+    let top = field[y0 * w + x0] * (1.0 - fx) + field[y0 * w + x1] * fx;
+    let bottom = field[y1 * w + x0] * (1.0 - fx) + field[y1 * w + x1] * fx;
+    top * (1.0 - fy) + bottom * fy
+}
+
+/// Like [`sample_bilinear`] but with smoothstep weights, so lattice noise has no
+/// visible creases.
+fn sample_smooth(field: &[f32], w: usize, h: usize, x: f32, y: f32) -> f32 {
+    let smooth = |t: f32| t * t * (3.0 - 2.0 * t);
+    let (x0f, y0f) = (x.floor(), y.floor());
+    let sx = x0f + smooth(x - x0f);
+    let sy = y0f + smooth(y - y0f);
+    sample_bilinear(field, w, h, sx, sy)
+}
+
+/// Mix a key and a counter into a fresh 64-bit key (SplitMix64 finalizer). The
+/// noise layer chains these to get an independent draw per (seed, step, cell).
+#[inline]
+fn noise_key(key: u64, counter: u64) -> u64 {
+    let mut z = key ^ counter.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 33)).wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Uniform `[0, 1)` from the top 24 bits of a key.
+#[inline]
+fn unit_float(key: u64) -> f32 {
+    (key >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Two independent standard normals from one key (Box–Muller).
+#[inline]
+fn gaussian_pair(key: u64) -> (f32, f32) {
+    let u1 = unit_float(key).max(1e-7);
+    let u2 = unit_float(noise_key(key, 1));
+    let r = (-2.0 * u1.ln()).sqrt();
+    let (sin, cos) = (std::f32::consts::TAU * u2).sin_cos();
+    (r * cos, r * sin)
+}
+
 /// Lenia growth: a bell curve on the neighborhood potential, mapped to `[-1, 1]`.
 #[inline]
 fn growth(u: f32, mu: f32, sigma: f32) -> f32 {
@@ -928,15 +2895,25 @@ fn sobel(field: &[f32], w: usize, h: usize, x: usize, y: usize) -> (f32, f32) {
     (gx, gy)
 }
 
-/// Build the shared radial kernel as normalized taps within `kernel_radius`.
-fn build_kernel(params: &FlowLeniaParams) -> Vec<Tap> {
-    let r = params.kernel_radius as i32;
+/// Visit every kernel tap: the integer offset, the ring profile there (with
+/// the shape's offset and eccentricity applied at the global orientation), the
+/// tap's polar angle `θ`, and its normalized radius. Taps with negligible ring
+/// weight are skipped.
+fn for_each_ring_tap(params: &FlowLeniaParams, mut f: impl FnMut(i32, i32, f32, f32, f32)) {
+    let shape = &params.shape;
     let rf = params.kernel_radius as f32;
-    let mut taps = Vec::new();
-    let mut sum = 0.0f32;
+    // An offset ring reaches past the nominal radius.
+    let r = (rf * (1.0 + shape.offset.abs())).ceil() as i32;
+    let (sin, cos) = shape.orientation.sin_cos();
+    let squash = (1.0 - shape.eccentricity * shape.eccentricity).max(1e-6);
     for dy in -r..=r {
         for dx in -r..=r {
-            let dist = ((dx * dx + dy * dy) as f32).sqrt();
+            // Tap position in the kernel's frame: `u` along the orientation
+            // (shifted by the ring offset), `v` across it.
+            let (px, py) = (dx as f32, dy as f32);
+            let u = px * cos + py * sin - shape.offset * rf;
+            let v = py * cos - px * sin;
+            let dist = (u * u + v * v / squash).sqrt();
             let n = dist / rf; // normalized radius
             if n > 1.0 || n <= 0.0 {
                 continue;
@@ -947,18 +2924,63 @@ fn build_kernel(params: &FlowLeniaParams) -> Vec<Tap> {
                 val += ring.weight * (-0.5 * d * d).exp();
             }
             if val > 1e-6 {
-                taps.push(Tap { dx, dy, w: val });
-                sum += val;
+                f(dx, dy, val, py.atan2(px), n);
             }
         }
     }
-    // Normalize so a uniform unit field convolves to exactly 1.
+}
+
+/// Build the shared kernel as normalized taps within `kernel_radius`, with the
+/// angular harmonic (if any) applied at the global orientation.
+fn build_kernel(params: &FlowLeniaParams) -> Vec<Tap> {
+    let shape = &params.shape;
+    let (k, amp) = (shape.harmonic as f32, shape.amplitude.clamp(0.0, 1.0));
+    let mut taps = Vec::new();
+    let mut sum = 0.0f32;
+    for_each_ring_tap(params, |dx, dy, val, theta, n| {
+        let val = if shape.harmonic > 0 {
+            val * (1.0 + amp * (k * (theta - shape.orientation) + shape.twist * n).cos())
+        } else {
+            val
+        };
+        taps.push(Tap { dx, dy, w: val });
+        sum += val;
+    });
+    // Normalize so a uniform unit field convolves to exactly 1.
+    if sum > 0.0 {
+        for tap in &mut taps {
+            tap.w /= sum;
+        }
+    }
+    taps
+}
+
+/// Build the harmonic basis for per-cell orientation, or `None` if the kernel
+/// has no angular harmonic. Parts are scaled so the base sums to 1.
+fn build_harmonic_basis(params: &FlowLeniaParams) -> Option<HarmonicBasis> {
+    let shape = &params.shape;
+    if shape.harmonic == 0 {
+        return None;
+    }
+    let k = shape.harmonic as f32;
+    let mut taps = Vec::new();
+    let mut sum = 0.0f32;
+    for_each_ring_tap(params, |dx, dy, val, theta, n| {
+        let (s, c) = (k * theta + shape.twist * n).sin_cos();
+        taps.push(HarmonicTap { dx, dy, w: val, wc: val * c, ws: val * s });
+        sum += val;
+    });
+    let (mut sum_c, mut sum_s) = (0.0f32, 0.0f32);
     if sum > 0.0 {
         for tap in &mut taps {
             tap.w /= sum;
+            tap.wc /= sum;
+            tap.ws /= sum;
+            sum_c += tap.wc;
+            sum_s += tap.ws;
         }
     }
-    taps
+    Some(HarmonicBasis { taps, sum_c, sum_s })
 }
 
 #[cfg(test)]
@@ -1156,6 +3178,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chemotaxis_draws_matter_toward_a_vent() {
+        // Same blob, same vent to its right; only β differs. Blind matter stays
+        // put, chemotactic matter climbs the energy gradient toward the food.
+        let drift = |beta: f32| {
+            let mut w = World::new(64, 64, test_params());
+            w.enable_energy(EnergyParams { chemotaxis: beta, ..EnergyParams::default() });
+            w.charge_energy(1.0);
+            w.add_source(48.0, 32.0, 6.0, 0.05);
+            w.seed_blob(0, 24.0, 32.0, 5.0, 0.9);
+            let initial = w.total_mass();
+            let (x0, _) = w.center_of_mass().unwrap();
+            for _ in 0..150 {
+                w.step();
+            }
+            let drift = (w.total_mass() - initial).abs() / initial;
+            assert!(drift < 1e-4, "chemotaxis must only move mass, drifted {drift}");
+            w.center_of_mass().unwrap().0 - x0
+        };
+        let blind = drift(0.0);
+        let seeking = drift(10.0);
+        assert!(blind.abs() < 0.1, "blind matter drifted {blind}");
+        assert!(seeking > 0.5, "chemotactic matter only moved {seeking} toward the vent");
+    }
+
+    #[test]
+    fn localized_chemotaxis_gene_moves_only_its_carrier() {
+        // Two blobs flank a central vent; only the left one carries β > 0.
+        let mut w = World::new(96, 64, test_params());
+        w.enable_genome();
+        w.enable_energy(EnergyParams::default());
+        w.charge_energy(1.0);
+        w.add_source(48.0, 32.0, 6.0, 0.05);
+        w.seed_blob(0, 24.0, 32.0, 5.0, 0.9);
+        w.seed_blob(0, 72.0, 32.0, 5.0, 0.9);
+        w.paint_chemotaxis(24.0, 32.0, 16.0, 10.0);
+        // Mass-weighted mean x over each half of the world.
+        let mean_x = |w: &World, lo: usize, hi: usize| {
+            let (mut m, mut mx) = (0.0f64, 0.0f64);
+            for y in 0..64 {
+                for x in lo..hi {
+                    let v = w.channel(0)[y * 96 + x] as f64;
+                    m += v;
+                    mx += v * x as f64;
+                }
+            }
+            mx / m
+        };
+        let (l0, r0) = (mean_x(&w, 0, 48), mean_x(&w, 48, 96));
+        for _ in 0..150 {
+            w.step();
+        }
+        let (l1, r1) = (mean_x(&w, 0, 48), mean_x(&w, 48, 96));
+        assert!(l1 - l0 > 0.5, "carrier should climb toward the vent: {l0} → {l1}");
+        assert!((r1 - r0).abs() < 0.1, "blind blob should stay: {r0} → {r1}");
+    }
+
     // ---- M-γ-3: closed-loop detritus recycling ---------------------------
 
     #[test]
@@ -1164,11 +3243,14 @@ mod tests {
         assert!(!world.detritus_enabled());
         assert!(world.detritus_field().is_none());
         assert!(world.total_detritus().is_none());
+        assert!(world.detritus_flux().is_none());
         // Enabling requires the energy economy.
         world.enable_energy(EnergyParams::default());
         world.enable_detritus(DetritusParams::default());
         assert!(world.detritus_enabled());
         assert_eq!(world.total_detritus(), Some(0.0));
+        assert_eq!(world.detritus_flux(), Some((0.0, 0.0)));
+        assert_eq!(world.detritus_totals(), Some((0.0, 0.0)));
     }
 
     #[test]
@@ -1195,10 +3277,19 @@ mod tests {
         assert!(initial > 0.0);
         let mut saw_detritus = false;
         for step in 0..200 {
+            let before = world.total_detritus().unwrap();
             world.step();
             let total = world.total_mass() + world.total_detritus().unwrap();
             let drift = (total - initial).abs() / initial;
             assert!(drift < 1e-4, "live+detritus drifted by {drift} at step {step}");
+            // The reported fluxes account for the detritus pool's change.
+            let (died, recycled) = world.detritus_flux().unwrap();
+            let change = world.total_detritus().unwrap() - before;
+            assert!((change - (died - recycled)).abs() < 1e-4 * initial, "flux at step {step}");
+            // The running totals account for the whole pool.
+            let (total_died, total_recycled) = world.detritus_totals().unwrap();
+            let pool = world.total_detritus().unwrap();
+            assert!((pool - (total_died - total_recycled)).abs() < 1e-4 * initial);
             if world.total_detritus().unwrap() > initial * 0.05 {
                 saw_detritus = true;
             }
@@ -1348,6 +3439,867 @@ mod tests {
         );
     }
 
+    // ---- Anisotropic kernels -----------------------------------------------
+
+    fn directed_params() -> FlowLeniaParams {
+        let mut p = test_params();
+        p.shape = KernelShape { harmonic: 1, amplitude: 0.6, ..KernelShape::default() };
+        p
+    }
+
+    /// Weight-averaged tap offset: where the kernel "looks".
+    fn kernel_lean(params: &FlowLeniaParams) -> (f32, f32) {
+        let k = build_kernel(params);
+        let mx: f32 = k.iter().map(|t| t.w * t.dx as f32).sum();
+        let my: f32 = k.iter().map(|t| t.w * t.dy as f32).sum();
+        (mx, my)
+    }
+
+    #[test]
+    fn anisotropic_kernels_are_normalized() {
+        let shapes = [
+            KernelShape { harmonic: 1, amplitude: 0.8, ..KernelShape::default() },
+            KernelShape { harmonic: 3, amplitude: 1.0, twist: 4.0, ..KernelShape::default() },
+            KernelShape { offset: 0.3, orientation: 1.0, ..KernelShape::default() },
+            KernelShape { eccentricity: 0.7, orientation: 0.4, ..KernelShape::default() },
+        ];
+        for shape in shapes {
+            let mut p = test_params();
+            p.shape = shape;
+            assert!(!p.shape.is_radial());
+            let sum: f32 = build_kernel(&p).iter().map(|t| t.w).sum();
+            assert!((sum - 1.0).abs() < 1e-4, "{shape:?} sums to {sum}");
+        }
+    }
+
+    #[test]
+    fn directed_kernels_lean_along_their_orientation() {
+        let (mx, my) = kernel_lean(&test_params());
+        assert!(mx.abs() < 1e-4 && my.abs() < 1e-4, "radial kernel leans ({mx}, {my})");
+
+        let mut p = directed_params();
+        let (mx, my) = kernel_lean(&p);
+        assert!(mx > 1.0 && my.abs() < 1e-3, "φ=0 should lean +x: ({mx}, {my})");
+        p.shape.orientation = std::f32::consts::FRAC_PI_2;
+        let (mx, my) = kernel_lean(&p);
+        assert!(my > 1.0 && mx.abs() < 1e-3, "φ=π/2 should lean +y: ({mx}, {my})");
+
+        let mut p = test_params();
+        p.shape.offset = 0.3;
+        let (mx, _) = kernel_lean(&p);
+        assert!(mx > 1.0, "offset ring should lean +x: {mx}");
+    }
+
+    #[test]
+    fn localized_orientation_matches_the_global_kernel() {
+        // With every cell's φ equal to the global orientation, the per-cell
+        // harmonic basis must reproduce the pre-oriented kernel.
+        let mut p = directed_params();
+        p.shape.twist = 2.0;
+        p.shape.orientation = 0.7;
+        let mut global = World::new(48, 48, p.clone());
+        let mut local = World::new(48, 48, p);
+        local.enable_genome();
+        for w in [&mut global, &mut local] {
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        }
+        for _ in 0..30 {
+            global.step();
+            local.step();
+        }
+        let diff = global
+            .channel(0)
+            .iter()
+            .zip(local.channel(0))
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0f32, f32::max);
+        assert!(diff < 1e-4, "basis and baked kernel diverged by {diff}");
+    }
+
+    #[test]
+    fn directed_kernel_moves_a_symmetric_blob() {
+        // A radially symmetric blob under a radial kernel has no reason to move;
+        // the first harmonic alone pushes it along the orientation (+x here). In
+        // the default static-spot regime the push is small but strictly directed.
+        let drift = |params: FlowLeniaParams| {
+            let mut world = World::new(64, 64, params);
+            world.seed_blob(0, 32.0, 32.0, 6.0, 0.9);
+            let (x0, y0) = world.center_of_mass().unwrap();
+            for _ in 0..100 {
+                world.step();
+            }
+            let (x1, y1) = world.center_of_mass().unwrap();
+            (torus_delta(x1, x0, 64.0), torus_delta(y1, y0, 64.0))
+        };
+        let (sx, sy) = drift(test_params());
+        assert!(sx.abs() < 1e-3 && sy.abs() < 1e-3, "radial blob drifted ({sx}, {sy})");
+        let (dx, dy) = drift(directed_params());
+        assert!(dx > 0.02, "directed blob only moved {dx} along its orientation");
+        assert!(dy.abs() < 1e-3, "directed blob strayed sideways by {dy}");
+    }
+
+    #[test]
+    fn mass_conserved_with_localized_orientation() {
+        let mut world = World::new(64, 64, directed_params());
+        world.enable_genome();
+        world.seed_blob(0, 20.0, 32.0, 6.0, 0.9);
+        world.seed_blob(0, 44.0, 32.0, 6.0, 0.9);
+        world.paint_orientation(44.0, 32.0, 12.0, std::f32::consts::PI);
+        let initial = world.total_mass();
+        for step in 0..100 {
+            world.step();
+            let drift = (world.total_mass() - initial).abs() / initial;
+            assert!(drift < 1e-4, "mass drifted by {drift} at step {step}");
+        }
+        // Orientation rides the mass: the painted blob keeps pointing backwards.
+        let orient = world.orientation_field().unwrap();
+        let (cx, cy) = (44usize, 32usize);
+        assert!((orient[cy * 64 + cx].abs() - std::f32::consts::PI).abs() < 0.2);
+    }
+
+    // ---- F4: diffusible signal channel -------------------------------------
+
+    #[test]
+    fn signal_disabled_by_default() {
+        let mut world = World::new(16, 16, test_params());
+        world.seed_blob(0, 8.0, 8.0, 4.0, 0.8);
+        world.step();
+        assert!(!world.signal_enabled());
+        assert!(world.signal_field().is_none());
+        assert!(world.total_signal().is_none());
+    }
+
+    #[test]
+    fn signal_is_secreted_by_mass_and_decays_without_it() {
+        let mut world = World::new(48, 48, test_params());
+        world.enable_signal(SignalParams::default());
+        world.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        let initial = world.total_mass();
+        for _ in 0..50 {
+            world.step();
+        }
+        let lit = world.total_signal().unwrap();
+        assert!(lit > 0.0, "occupied world should carry signal");
+        let drift = (world.total_mass() - initial).abs() / initial;
+        assert!(drift < 1e-4, "signal must not move mass by itself, drifted {drift}");
+        // Signal peaks under the blob and falls off away from it.
+        let s = world.signal_field().unwrap();
+        assert!(s[24 * 48 + 24] > 10.0 * s[2 * 48 + 2]);
+
+        // An empty world sharing the same signal only loses it.
+        let mut empty = World::new(48, 48, test_params());
+        empty.enable_signal(SignalParams::default());
+        if let Some(sig) = empty.signal.as_mut() {
+            sig.field = world.signal_field().unwrap().to_vec();
+        }
+        for _ in 0..50 {
+            empty.step();
+        }
+        assert!(empty.total_signal().unwrap() < 0.1 * lit);
+    }
+
+    #[test]
+    fn signal_taxis_draws_a_listener_toward_an_emitter() {
+        // A silent listener sits left of a loud, deaf emitter. With taxis on,
+        // the listener climbs the emitter's signal gradient and drags the
+        // overall center of mass right; with it off, nothing moves.
+        let drift = |taxis: f32| {
+            let mut w = World::new(96, 64, test_params());
+            w.enable_genome();
+            w.enable_signal(SignalParams { taxis, decay: 0.01, ..SignalParams::default() });
+            w.seed_blob(0, 36.0, 32.0, 5.0, 0.9);
+            w.seed_blob(0, 60.0, 32.0, 5.0, 0.9);
+            w.paint_signal_genes(36.0, 32.0, 10.0, 0.0, 1.0);
+            w.paint_signal_genes(60.0, 32.0, 10.0, 0.2, 0.0);
+            let initial = w.total_mass();
+            let (x0, _) = w.center_of_mass().unwrap();
+            for _ in 0..150 {
+                w.step();
+            }
+            let drift = (w.total_mass() - initial).abs() / initial;
+            assert!(drift < 1e-4, "signal taxis must only move mass, drifted {drift}");
+            w.center_of_mass().unwrap().0 - x0
+        };
+        let deaf = drift(0.0);
+        let listening = drift(5.0);
+        assert!(deaf.abs() < 0.1, "deaf listener drifted {deaf}");
+        assert!(listening > 0.5, "listener only moved {listening} toward the emitter");
+    }
+
+    // ---- F5: predator–prey coupling -----------------------------------------
+
+    fn two_channel_params() -> FlowLeniaParams {
+        FlowLeniaParams { channels: 2, ..test_params() }
+    }
+
+    #[test]
+    fn predation_converts_prey_and_conserves_mass() {
+        // Predator (channel 1) overlaps the prey blob (channel 0) on one flank.
+        let mut w = World::new(64, 64, two_channel_params());
+        w.seed_blob(0, 32.0, 32.0, 7.0, 0.8);
+        w.seed_blob(1, 36.0, 32.0, 5.0, 0.8);
+        w.enable_trophic(vec![TrophicLink { predator: 1, prey: 0, rate: 0.5, energy_yield: 0.0 }]);
+        let initial = w.total_mass();
+        let (prey0, pred0) = (w.channel_mass(0), w.channel_mass(1));
+        for _ in 0..40 {
+            w.step();
+            assert!(w.channel(0).iter().all(|&v| v >= 0.0), "prey went negative");
+        }
+        let drift = (w.total_mass() - initial).abs() / initial;
+        assert!(drift < 1e-4, "predation must only relabel mass, drifted {drift}");
+        assert!(w.channel_mass(0) < 0.9 * prey0, "prey {prey0} → {}", w.channel_mass(0));
+        assert!(w.channel_mass(1) > pred0, "predator {pred0} → {}", w.channel_mass(1));
+    }
+
+    #[test]
+    fn disjoint_channels_do_not_interact() {
+        // Far-apart predator and prey: no overlap, no meal.
+        let mut w = World::new(96, 64, two_channel_params());
+        w.seed_blob(0, 24.0, 32.0, 5.0, 0.8);
+        w.seed_blob(1, 72.0, 32.0, 5.0, 0.8);
+        w.enable_trophic(vec![TrophicLink { predator: 1, prey: 0, rate: 0.5, energy_yield: 0.0 }]);
+        let prey0 = w.channel_mass(0);
+        for _ in 0..20 {
+            w.step();
+        }
+        assert!((w.channel_mass(0) - prey0).abs() / prey0 < 1e-4);
+    }
+
+    #[test]
+    fn predation_releases_energy() {
+        let run = |energy_yield: f32| {
+            let mut w = World::new(48, 48, two_channel_params());
+            let params = EnergyParams { maintain: 0.0, consume: 0.0, ..EnergyParams::default() };
+            w.enable_energy(params);
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.8);
+            w.seed_blob(1, 24.0, 24.0, 6.0, 0.8);
+            w.enable_trophic(vec![TrophicLink { predator: 1, prey: 0, rate: 0.5, energy_yield }]);
+            w.step();
+            w.total_energy().unwrap()
+        };
+        assert_eq!(run(0.0), 0.0);
+        assert!(run(1.0) > 0.0, "a meal should release energy");
+    }
+
+    #[test]
+    #[should_panic(expected = "prey on itself")]
+    fn self_predation_is_rejected() {
+        let mut w = World::new(16, 16, two_channel_params());
+        w.enable_trophic(vec![TrophicLink { predator: 1, prey: 1, rate: 0.5, energy_yield: 0.0 }]);
+    }
+
+    // ---- F6: environment fields ---------------------------------------------
+
+    #[test]
+    fn environment_generators_stay_in_range() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let lin = linear_gradient(32, 16, 0.0);
+        assert_eq!((lin[0], lin[31]), (-1.0, 1.0));
+        assert!(lin.windows(2).take(31).all(|p| p[1] > p[0]), "gradient rises with x");
+        let patches = patch_field(32, 16, 4, 4.0, &mut rng);
+        let noise = noise_field(32, 16, 8.0, 3, &mut rng);
+        for field in [patches, noise] {
+            assert_eq!(field.len(), 32 * 16);
+            assert!(field.iter().all(|v| (-1.0..=1.0).contains(v)));
+            let lo = field.iter().copied().fold(f32::MAX, f32::min);
+            let hi = field.iter().copied().fold(f32::MIN, f32::max);
+            assert!(hi - lo > 0.1, "pattern should vary, spans {lo}..{hi}");
+        }
+    }
+
+    #[test]
+    fn inert_environment_changes_nothing() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        let mut plain = World::new(48, 48, test_params());
+        let mut env = World::new(48, 48, test_params());
+        let field = noise_field(48, 48, 12.0, 2, &mut rng);
+        env.enable_environment(EnvironmentParams::default(), field);
+        for w in [&mut plain, &mut env] {
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+            for _ in 0..20 {
+                w.step();
+            }
+        }
+        assert_eq!(plain.channel(0), env.channel(0));
+    }
+
+    #[test]
+    fn uniform_environment_matches_a_global_shift() {
+        // T ≡ 1 with mu_shift = δ is the same rule as growth_mu + δ.
+        let shifted = FlowLeniaParams { growth_mu: 0.16, ..test_params() };
+        let mut global = World::new(48, 48, shifted);
+        let mut env = World::new(48, 48, test_params());
+        let params = EnvironmentParams { mu_shift: 0.01, ..Default::default() };
+        env.enable_environment(params, vec![1.0; 48 * 48]);
+        for w in [&mut global, &mut env] {
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+            for _ in 0..20 {
+                w.step();
+            }
+        }
+        let diff = global
+            .channel(0)
+            .iter()
+            .zip(env.channel(0))
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0f32, f32::max);
+        assert!(diff < 1e-5, "max diff {diff}");
+    }
+
+    #[test]
+    fn frozen_zone_holds_matter_still() {
+        // dt_scale = 1 with T = −1 zeroes local dt: nothing moves.
+        let mut w = World::new(48, 48, test_params());
+        let params = EnvironmentParams { dt_scale: 1.0, ..Default::default() };
+        w.enable_environment(params, vec![-1.0; 48 * 48]);
+        w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        let before = w.channel(0).to_vec();
+        for _ in 0..10 {
+            w.step();
+        }
+        assert_eq!(w.channel(0), before.as_slice());
+    }
+
+    #[test]
+    fn harsh_zone_costs_more_upkeep() {
+        // Two equal blobs in equal energy; the one in the T = +1 half pays double.
+        let mut w = World::new(64, 32, test_params());
+        let field: Vec<f32> = (0..64 * 32).map(|i| if i % 64 < 32 { -1.0 } else { 1.0 }).collect();
+        w.enable_energy(EnergyParams {
+            diffusion: 0.0,
+            consume: 0.0,
+            maintain: 0.05,
+            ..EnergyParams::default()
+        });
+        let params = EnvironmentParams { maintain_scale: 1.0, ..Default::default() };
+        w.enable_environment(params, field);
+        w.charge_energy(1.0);
+        w.seed_blob(0, 16.0, 16.0, 4.0, 0.9);
+        w.seed_blob(0, 48.0, 16.0, 4.0, 0.9);
+        w.step();
+        let e = w.energy_field().unwrap();
+        let (mild, harsh) = (e[16 * 64 + 16], e[16 * 64 + 48]);
+        assert!(mild > harsh, "mild zone {mild} should keep more energy than harsh {harsh}");
+    }
+
+    #[test]
+    fn drifting_environment_translates_and_conserves_mass() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+        let base = noise_field(32, 32, 8.0, 2, &mut rng);
+        let mut w = World::new(32, 32, test_params());
+        let params = EnvironmentParams { mu_shift: 0.02, drift: (1.0, 0.0), ..Default::default() };
+        w.enable_environment(params, base.clone());
+        w.seed_blob(0, 16.0, 16.0, 5.0, 0.9);
+        let initial = w.total_mass();
+        for _ in 0..3 {
+            w.step();
+        }
+        let t = w.environment_field().unwrap();
+        for y in 0..32 {
+            for x in 0..32 {
+                let expect = base[y * 32 + (x + 29) % 32];
+                assert!((t[y * 32 + x] - expect).abs() < 1e-5);
+            }
+        }
+        let drift = (w.total_mass() - initial).abs() / initial;
+        assert!(drift < 1e-4, "environment must not create mass, drifted {drift}");
+    }
+
+    // ---- F7: schedules ------------------------------------------------------
+
+    #[test]
+    fn schedules_evaluate_their_waveforms() {
+        let sine = Schedule::Sine { base: 1.0, amplitude: 0.5, period: 8.0, phase: 0.0 };
+        assert!((sine.value(0) - 1.0).abs() < 1e-6);
+        assert!((sine.value(2) - 1.5).abs() < 1e-6);
+        assert!((sine.value(6) - 0.5).abs() < 1e-6);
+        let steps = Schedule::Steps { initial: 0.0, changes: vec![(3, 1.0), (5, 2.0)] };
+        let got: Vec<f32> = (0..7).map(|t| steps.value(t)).collect();
+        assert_eq!(got, [0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0]);
+        let ramp = Schedule::Ramp { from: 1.0, to: 3.0, start: 2, end: 6 };
+        let got: Vec<f32> = (0..8).map(|t| ramp.value(t)).collect();
+        assert_eq!(got, [1.0, 1.0, 1.0, 1.5, 2.0, 2.5, 3.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sorted by step")]
+    fn unsorted_step_changes_are_rejected() {
+        let mut world = World::new(16, 16, test_params());
+        let steps = Schedule::Steps { initial: 0.15, changes: vec![(10, 0.17), (5, 0.16)] };
+        world.add_schedule(ScheduleTarget::GrowthMu, steps);
+    }
+
+    #[test]
+    fn scheduled_step_change_matches_a_manual_one() {
+        let mut scheduled = World::new(48, 48, test_params());
+        scheduled.add_schedule(
+            ScheduleTarget::GrowthMu,
+            Schedule::Steps { initial: 0.15, changes: vec![(10, 0.17)] },
+        );
+        let mut manual = World::new(48, 48, test_params());
+        for w in [&mut scheduled, &mut manual] {
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        }
+        for t in 0..20 {
+            if t == 10 {
+                manual.set_params(FlowLeniaParams { growth_mu: 0.17, ..test_params() });
+            }
+            scheduled.step();
+            manual.step();
+        }
+        assert_eq!(scheduled.time(), 20);
+        assert_eq!(scheduled.params().growth_mu, 0.17);
+        assert_eq!(scheduled.channel(0), manual.channel(0));
+    }
+
+    #[test]
+    fn kernel_rebuilds_only_when_rings_change() {
+        let mut w = World::new(32, 32, test_params());
+        w.seed_blob(0, 16.0, 16.0, 5.0, 0.9);
+        w.add_schedule(
+            ScheduleTarget::Dt,
+            Schedule::Sine { base: 0.1, amplitude: 0.05, period: 10.0, phase: 0.0 },
+        );
+        for _ in 0..10 {
+            w.step();
+        }
+        assert_eq!(w.kernel_builds, 1, "a dt season must not touch the kernel");
+        // A ramp that holds still after step 12 rebuilds only while it moves.
+        w.add_schedule(
+            ScheduleTarget::RingPeak(0),
+            Schedule::Ramp { from: 0.5, to: 0.6, start: 10, end: 12 },
+        );
+        for _ in 0..10 {
+            w.step();
+        }
+        assert_eq!(w.kernel_builds, 3, "two distinct ring peaks after the first");
+        assert_eq!(w.params().rings[0].peak, 0.6);
+    }
+
+    #[test]
+    fn seasonal_sources_modulate_injection() {
+        // Sources off for the first 5 steps, full on after: energy appears late.
+        let mut w = World::new(32, 32, test_params());
+        w.enable_energy(EnergyParams::default());
+        w.add_source(16.0, 16.0, 4.0, 0.1);
+        w.add_schedule(
+            ScheduleTarget::SourceRate,
+            Schedule::Steps { initial: 0.0, changes: vec![(5, 1.0)] },
+        );
+        for _ in 0..5 {
+            w.step();
+        }
+        assert_eq!(w.total_energy().unwrap(), 0.0);
+        w.step();
+        assert!(w.total_energy().unwrap() > 0.0);
+    }
+
+    // ---- F8: thermal noise --------------------------------------------------
+
+    fn noisy_world(seed: u64) -> World {
+        let mut w = World::new(48, 48, test_params());
+        w.enable_noise(NoiseParams { seed, flow: 0.5, exchange: 0.5 });
+        w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        w
+    }
+
+    #[test]
+    fn noise_is_seeded_and_conserves_mass() {
+        let (mut a, mut b, mut c) = (noisy_world(1), noisy_world(1), noisy_world(2));
+        let initial = a.total_mass();
+        for _ in 0..30 {
+            a.step();
+            b.step();
+            c.step();
+        }
+        assert_eq!(a.channel(0), b.channel(0), "same seed, same run");
+        assert_ne!(a.channel(0), c.channel(0), "different seeds should diverge");
+        let drift = (a.total_mass() - initial).abs() / initial;
+        assert!(drift < 1e-4, "noise must only move mass, drifted {drift}");
+        assert!(a.channel(0).iter().all(|&v| v >= -1e-6), "exchange drove a cell negative");
+    }
+
+    #[test]
+    fn noise_differs_from_the_deterministic_run() {
+        let mut quiet = World::new(48, 48, test_params());
+        quiet.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        let mut noisy = noisy_world(7);
+        for _ in 0..10 {
+            quiet.step();
+            noisy.step();
+        }
+        assert_ne!(quiet.channel(0), noisy.channel(0));
+    }
+
+    #[test]
+    fn noisy_snapshot_replays_exactly() {
+        // Save mid-run by cloning; the restored copy replays the same noise.
+        let mut world = noisy_world(3);
+        for _ in 0..10 {
+            world.step();
+        }
+        let mut restored = world.clone();
+        for _ in 0..10 {
+            world.step();
+            restored.step();
+        }
+        assert_eq!(world.time(), 20);
+        assert_eq!(world.channel(0), restored.channel(0));
+        // Reseeding a snapshot gives an independent replicate.
+        let mut replicate = world.clone();
+        replicate.set_noise_seed(99);
+        world.step();
+        replicate.step();
+        assert_ne!(world.channel(0), replicate.channel(0));
+    }
+
+    // ---- Diagnostics ----------------------------------------------------------
+
+    #[test]
+    fn diagnostics_observe_without_changing_the_run() {
+        let mut plain = World::new(48, 48, test_params());
+        let mut diag = World::new(48, 48, test_params());
+        diag.enable_diagnostics();
+        assert!(plain.flow_field(0).is_none() && plain.alpha_field().is_none());
+        for w in [&mut plain, &mut diag] {
+            w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+            for _ in 0..5 {
+                w.step();
+            }
+        }
+        assert_eq!(plain.channel(0), diag.channel(0));
+        // Affinity is the growth map of the recorded potential.
+        let p = test_params();
+        let (pot, u) = (diag.potential_field(0).unwrap(), diag.affinity(0));
+        for i in (0..48 * 48).step_by(37) {
+            assert!((u[i] - growth(pot[i], p.growth_mu, p.growth_sigma)).abs() < 1e-6);
+        }
+        // α follows its ramp on the matter the flow acted on.
+        let (alpha, m) = (diag.alpha_field().unwrap(), diag.transported_mass(0).unwrap());
+        for i in (0..48 * 48).step_by(37) {
+            let expect = ((m[i] / p.theta_a).powf(p.alpha_n)).clamp(0.0, 1.0);
+            assert!((alpha[i] - expect).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn clamp_mask_marks_capped_displacements() {
+        let params = FlowLeniaParams { max_flow: 1e-4, ..test_params() };
+        let mut w = World::new(48, 48, params);
+        w.enable_diagnostics();
+        w.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        w.step();
+        let (fx, fy) = w.flow_field(0).unwrap();
+        let mask = w.clamp_mask(0).unwrap();
+        let dt = w.params().dt;
+        let mut clamped = 0;
+        for i in 0..48 * 48 {
+            let mag = (fx[i] * fx[i] + fy[i] * fy[i]).sqrt() * dt;
+            assert_eq!(mask[i], mag > 1e-4, "cell {i}: |F|·dt = {mag}");
+            clamped += mask[i] as usize;
+        }
+        assert!(clamped > 0, "a tiny max_flow should clamp somewhere");
+    }
+
+    // ---- Invariant validation -------------------------------------------------
+
+    #[test]
+    fn validator_passes_a_healthy_run() {
+        let mut w = noisy_world(4);
+        w.enable_validation(1e-4);
+        for _ in 0..20 {
+            assert_eq!(w.try_step(), Ok(()));
+        }
+        assert!(w.validate().is_ok());
+    }
+
+    #[test]
+    fn validator_names_the_broken_field_cell_and_step() {
+        let mut w = World::new(16, 16, test_params());
+        w.enable_energy(EnergyParams::default());
+        w.seed_blob(0, 8.0, 8.0, 3.0, 0.9);
+        w.enable_validation(1e-4);
+        w.step();
+        w.channel_mut(0)[3 * 16 + 5] = f32::NAN;
+        assert!(matches!(
+            w.validate(),
+            Err(InvariantViolation::NonFinite {
+                field: InvariantField::Matter(0),
+                x: 5,
+                y: 3,
+                step: 1,
+                ..
+            })
+        ));
+        // The per-step validator latches the first violation it sees.
+        let err = w.try_step().unwrap_err();
+        assert!(matches!(err, InvariantViolation::NonFinite { step: 2, .. }), "{err}");
+        assert!(matches!(w.violation(), Some(InvariantViolation::NonFinite { step: 2, .. })));
+    }
+
+    #[test]
+    fn validator_catches_negative_matter_and_drift() {
+        let mut w = World::new(16, 16, test_params());
+        w.seed_blob(0, 8.0, 8.0, 3.0, 0.9);
+        w.channel_mut(0)[0] = -0.5;
+        assert!(matches!(
+            w.validate(),
+            Err(InvariantViolation::Negative { field: InvariantField::Matter(0), x: 0, y: 0, .. })
+        ));
+        // Mass injected between steps shows up as drift from the baseline.
+        let mut w = World::new(16, 16, test_params());
+        w.seed_blob(0, 8.0, 8.0, 3.0, 0.9);
+        w.enable_validation(1e-4);
+        w.step();
+        w.seed_blob(0, 2.0, 2.0, 2.0, 0.5);
+        assert!(matches!(w.try_step(), Err(InvariantViolation::MassDrift { step: 2, .. })));
+    }
+
+    // ---- Rescaling --------------------------------------------------------------
+
+    #[test]
+    fn rescaling_preserves_mass_in_kernel_units_and_scales_the_rule() {
+        let mut w = World::new(48, 48, test_params());
+        w.enable_energy(EnergyParams::default());
+        w.enable_detritus(DetritusParams::default());
+        w.charge_energy(1.0);
+        w.seed_blob(0, 20.0, 24.0, 6.0, 0.9);
+        for _ in 0..5 {
+            w.step();
+        }
+        let r0 = test_params().kernel_radius;
+        // Mass per kernel area, Σ / R², is the invariant.
+        let per_kernel = |m: f64, radius: usize| m / (radius * radius) as f64;
+        let mass = per_kernel(w.total_mass(), r0);
+        let detritus = per_kernel(w.total_detritus().unwrap(), r0);
+        for (n, radius) in [(96, 2 * r0), (24, r0 / 2), (60, r0 + 3), (96, r0)] {
+            let r = w.rescaled(n, n, radius);
+            let got = per_kernel(r.total_mass(), radius);
+            assert!((got - mass).abs() / mass < 1e-6, "{n}²: mass {got} vs {mass}");
+            let got = per_kernel(r.total_detritus().unwrap(), radius);
+            assert!((got - detritus).abs() / detritus < 1e-6, "{n}²: detritus {got}");
+            assert_eq!(r.time(), 5);
+            assert_eq!(r.params().kernel_radius, radius);
+        }
+        // The physics follows the kernel radius, not the grid.
+        let p = test_params();
+        let big = w.rescaled(96, 96, 2 * r0);
+        assert!((big.params().dt - 4.0 * p.dt).abs() < 1e-6);
+        assert!((big.params().max_flow - 2.0 * p.max_flow).abs() < 1e-6);
+        assert!((big.energy_field().unwrap()[0] - w.energy_field().unwrap()[0]).abs() < 1e-4);
+        let stretched = w.rescaled(96, 96, r0);
+        assert_eq!((stretched.params().dt, stretched.params().max_flow), (p.dt, p.max_flow));
+    }
+
+    #[test]
+    fn rescaling_round_trips() {
+        // Promote a blob to 2× and bring it back down: the box average undoes
+        // the interpolation up to its smoothing.
+        let mut coarse = World::new(48, 48, test_params());
+        coarse.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        let r0 = test_params().kernel_radius;
+        let back = coarse.rescaled(96, 96, 2 * r0).rescaled(48, 48, r0);
+        assert_eq!(back.params(), coarse.params());
+        let l1: f64 = coarse
+            .channel(0)
+            .iter()
+            .zip(back.channel(0))
+            .map(|(x, y)| (x - y).abs() as f64)
+            .sum();
+        assert!(l1 / coarse.total_mass() < 0.02, "round trip L1 {l1}");
+    }
+
+    #[test]
+    fn rescaled_creature_behaves_the_same() {
+        // Run a blob at 48² and its 2× promotion side by side, then bring the
+        // fine run back down: the two should agree closely.
+        let r0 = test_params().kernel_radius;
+        let mut coarse = World::new(48, 48, test_params());
+        coarse.seed_blob(0, 24.0, 24.0, 6.0, 0.9);
+        let mut fine = coarse.rescaled(96, 96, 2 * r0);
+        let start = coarse.channel(0).to_vec();
+        for _ in 0..60 {
+            coarse.step();
+            fine.step();
+        }
+        let back = fine.rescaled(48, 48, r0);
+        let l1 = |a: &[f32], b: &[f32]| -> f64 {
+            a.iter().zip(b).map(|(x, y)| (x - y).abs() as f64).sum::<f64>() / coarse.total_mass()
+        };
+        let (rel, moved) = (l1(coarse.channel(0), back.channel(0)), l1(&start, coarse.channel(0)));
+        // The scales agree far better than the pattern changed over the run.
+        assert!(rel < 0.1 && rel < 0.5 * moved, "diverged: L1 {rel} vs change {moved}");
+    }
+
+    #[test]
+    fn rescaling_carries_the_genome() {
+        let mut w = World::new(32, 32, test_params());
+        w.enable_genome();
+        w.paint_genome(8.0, 16.0, 6.0, 0.2, 0.02);
+        w.paint_orientation(8.0, 16.0, 6.0, 3.0);
+        let big = w.rescaled(64, 64, 2 * test_params().kernel_radius);
+        let (mu, orient) = (big.mu_field().unwrap(), big.orientation_field().unwrap());
+        assert!((mu[32 * 64 + 16] - 0.2).abs() < 1e-5);
+        assert!((mu[32 * 64 + 48] - test_params().growth_mu).abs() < 1e-5);
+        assert!((orient[32 * 64 + 16] - 3.0).abs() < 1e-4);
+    }
+
+    // ---- sparse stepping ------------------------------------------------------
+
+    /// A small kernel keeps the sparse tests cheap and the reach short.
+    fn sparse_params() -> FlowLeniaParams {
+        FlowLeniaParams { kernel_radius: 5, ..test_params() }
+    }
+
+    /// Run a compact patch in a mostly empty world dense and sparse side by
+    /// side; the two must stay bitwise identical. Returns the sparse world.
+    fn assert_sparse_matches_dense(params: FlowLeniaParams, setup: impl Fn(&mut World)) -> World {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let mut dense = World::new(128, 96, params);
+        dense.seed_random_patch(&mut rng, 0, 20.0, 24.0, 5.0, 0.9);
+        setup(&mut dense);
+        let mut sparse = dense.clone();
+        sparse.enable_sparse(0.0);
+        for step in 0..25 {
+            dense.step();
+            sparse.step();
+            assert_eq!(dense.channel(0), sparse.channel(0), "diverged at step {step}");
+        }
+        sparse
+    }
+
+    #[test]
+    fn sparse_stepping_is_exactly_the_dense_step() {
+        assert_sparse_matches_dense(sparse_params(), |_| {});
+        assert_sparse_matches_dense(sparse_params(), |w| {
+            w.enable_energy(EnergyParams::default());
+            w.enable_noise(NoiseParams { seed: 5, flow: 0.5, exchange: 0.5 });
+        });
+        // A second patch across the wrap: reach must be measured on the torus.
+        assert_sparse_matches_dense(sparse_params(), |w| {
+            w.seed_random_patch(&mut rand::rngs::StdRng::seed_from_u64(2), 0, 126.0, 2.0, 4.0, 0.8);
+        });
+        let mut shaped = sparse_params();
+        shaped.shape = KernelShape { harmonic: 1, amplitude: 0.8, ..KernelShape::default() };
+        assert_sparse_matches_dense(shaped, |w| {
+            w.enable_genome();
+            w.paint_orientation(20.0, 24.0, 8.0, 1.0);
+        });
+    }
+
+    #[test]
+    fn sparse_stepping_skips_empty_tiles() {
+        // Exact mode: the compact patch leaves genuinely empty regions.
+        let sparse = assert_sparse_matches_dense(sparse_params(), |_| {});
+        let active = sparse.active_tile_fraction().unwrap();
+        assert!(active < 0.5, "one patch should leave most tiles idle, {active}");
+        let mut empty = World::new(64, 64, sparse_params());
+        empty.enable_sparse(0.0);
+        empty.step();
+        assert_eq!(empty.active_tile_fraction(), Some(0.0));
+        assert_eq!(World::new(16, 16, sparse_params()).active_tile_fraction(), None);
+    }
+
+    #[test]
+    fn sparse_epsilon_ignores_smooth_tails() {
+        // A Gaussian seed is nonzero far out: exact tracking keeps nearly every
+        // tile, a tiny epsilon skips the tails with a negligible error.
+        let mut dense = World::new(160, 160, sparse_params());
+        dense.seed_blob(0, 40.0, 40.0, 5.0, 0.9);
+        let (mut exact, mut loose) = (dense.clone(), dense.clone());
+        exact.enable_sparse(0.0);
+        loose.enable_sparse(1e-6);
+        for _ in 0..10 {
+            dense.step();
+            exact.step();
+            loose.step();
+        }
+        assert!(exact.active_tile_fraction().unwrap() > 0.9);
+        let active = loose.active_tile_fraction().unwrap();
+        assert!(active < 0.5, "tails should not keep tiles active, {active}");
+        let err: f64 =
+            dense.channel(0).iter().zip(loose.channel(0)).map(|(a, b)| (a - b).abs() as f64).sum();
+        assert!(err / dense.total_mass() < 1e-4, "relative L1 error {err}");
+    }
+
+    // ---- batched stepping ------------------------------------------------------
+
+    #[test]
+    fn batch_steps_each_world_exactly_as_alone() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(12);
+        let mut worlds = Vec::new();
+        for k in 0..4 {
+            let mut p = test_params();
+            p.growth_mu += 0.01 * k as f32;
+            p.rings[0].width *= 1.0 + 0.2 * k as f32;
+            if k == 2 {
+                p.shape = KernelShape { harmonic: 2, amplitude: 0.5, ..KernelShape::default() };
+            }
+            let mut world = World::new(40, 40, p);
+            world.seed_random_patch(&mut rng, 0, 20.0, 20.0, 12.0, 0.7);
+            if k == 1 {
+                world.enable_energy(EnergyParams::default());
+                world.enable_noise(NoiseParams { seed: 4, flow: 0.3, exchange: 0.3 });
+            }
+            if k == 3 {
+                world.enable_genome();
+                world.paint_genome(20.0, 20.0, 6.0, 0.2, 0.02);
+                world.add_schedule(
+                    ScheduleTarget::KernelRadius,
+                    Schedule::Steps { initial: 13.0, changes: vec![(10, 10.0)] },
+                );
+            }
+            worlds.push(world);
+        }
+        let mut alone = worlds.clone();
+        let mut batch = WorldBatch::new(worlds);
+        assert_eq!(batch.len(), 4);
+        for _ in 0..25 {
+            batch.step();
+            alone.iter_mut().for_each(World::step);
+        }
+        for (b, a) in batch.worlds().iter().zip(&alone) {
+            assert_eq!(b.time(), a.time());
+            assert_eq!(b.channel(0), a.channel(0));
+            assert_eq!(b.mu_field(), a.mu_field());
+        }
+        assert!(batch.worlds()[0].channel(0) != batch.worlds()[1].channel(0));
+    }
+
+    #[test]
+    fn batch_keeps_each_worlds_sparse_mask() {
+        // A loose and an exact sparse world next to a dense one: each must
+        // match stepping it alone, tile mask included.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(13);
+        let mut worlds = Vec::new();
+        for epsilon in [Some(1e-3), Some(0.0), None] {
+            let mut world = World::new(128, 64, sparse_params());
+            world.seed_random_patch(&mut rng, 0, 24.0, 32.0, 6.0, 0.8);
+            world.seed_blob(0, 100.0, 32.0, 3.0, 0.6);
+            if let Some(epsilon) = epsilon {
+                world.enable_sparse(epsilon);
+            }
+            worlds.push(world);
+        }
+        let mut alone = worlds.clone();
+        let mut batch = WorldBatch::new(worlds);
+        for _ in 0..10 {
+            batch.step();
+            alone.iter_mut().for_each(World::step);
+        }
+        for (b, a) in batch.worlds().iter().zip(&alone) {
+            assert_eq!(b.channel(0), a.channel(0));
+            assert_eq!(b.active_tile_fraction(), a.active_tile_fraction());
+        }
+        assert!(alone[0].active_tile_fraction().unwrap() < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "share a size")]
+    fn batch_rejects_mismatched_sizes() {
+        WorldBatch::new(vec![World::new(32, 32, test_params()), World::new(16, 32, test_params())]);
+    }
+
     /// Min/max localized μ over cells carrying meaningful mass.
     fn occupied_mu_span(world: &World, thresh: f32) -> (f32, f32) {
         let mass = world.channel(0);
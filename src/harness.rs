@@ -8,20 +8,51 @@
 //! What it computes:
 //! - **Field stats** — total mass, occupied fraction, spatial (Shannon) entropy
 //!   and a derived localization/concentration score, peak density, variance.
+//! - **Spatial scale** — radially averaged power spectrum and two-point
+//!   correlation function, with the dominant wavelength and correlation
+//!   length: stripes versus isolated spots versus uniform soup.
+//! - **Compression complexity** — quantize frames, parse spatial and spacetime
+//!   blocks with an LZ76 compressor, and track normalized and statistical
+//!   complexity over the run, on the same scale as the discrete substrate's
+//!   (the meter lives with the discrete metrics in [`emergence`](crate::emergence)).
 //! - **Connected components** — threshold the field and label blobs with
 //!   toroidal 8-connectivity; report per-blob cell count, mass, and centroid.
 //!   The continuous analog of "how many organisms, and how big."
+//! - **Morphology** — per-blob shape: principal axis and elongation,
+//!   compactness, holes (Euler number), rotational-symmetry order from the
+//!   angular harmonics of the mass, and a radial profile. "What shape."
 //! - **Temporal metrics** — field activity (per-step L1 change), and a `Tracker`
 //!   that matches blobs across frames to recover a velocity distribution
 //!   (center-of-mass drift — a plain observable, no movement black box).
+//! - **Narrative events** — births, deaths, splits and merges of blobs heavier
+//!   than a noise floor, from the persistent identities of a
+//!   [`BlobTracker`](crate::tracking::BlobTracker): the continuous counterpart
+//!   of the discrete grid's `NarrativeTracker`.
+//! - **Species** — cluster the localized `(μ, σ)` genome field (M-γ-1) into
+//!   species by density in genome space, track them across samples, and report
+//!   abundance, richness, Shannon/Simpson diversity, and extinctions.
+//! - **Evolutionary activity** — Bedau–Packard statistics over the genome:
+//!   discretize it into components, integrate each one's mass over time, and
+//!   compare against a shuffled neutral shadow to find adaptively significant
+//!   persistence; report diversity, total, mean and new activity, and Bedau's
+//!   class of evolutionary dynamics.
+//! - **Channel masses** — per-channel mass over time, the population curves of
+//!   predator and prey once channels are trophically coupled.
+//! - **Economy** — when the layers are on: stored energy, live versus dead
+//!   mass, the turnover of matter through death and recycling, and the
+//!   diversity of the genome field.
+//! - **Diagnostics** — reductions over the intermediate fields a world records
+//!   in diagnostics mode: mean flow speed, clamped fraction, energy gating.
+//! - **Replicates** — rerun one initial condition under different noise seeds
+//!   to measure how robust its behavior is to thermal noise.
 //! - **`RunSummary`** — folds a whole run into a handful of behavior descriptors
-//!   suitable as axes for the F2 outer-loop (MAP-Elites) search.
-//!
-//! Deferred: Bedau–Packard evolutionary activity statistics need a heritable
-//! component to track, which arrives with parameter localization (M-γ-1). They
-//! belong here once there is a genotype to count.
+//!   suitable as axes for the F2 outer-loop (MAP-Elites) search. A whole
+//!   `WorldBatch` can be measured in lockstep, one summary per world.
 
-use crate::flow_lenia::World;
+use crate::emergence::{ComplexityMeter, CompressionComplexity, CompressionConfig};
+use crate::flow_lenia::{World, WorldBatch};
+use crate::observer::{drive, drive_batch, Observer};
+use crate::tracking::{BlobTracker, TrackConfig};
 
 /// Scalar reductions of a single field snapshot.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -134,6 +165,268 @@ pub fn field_stats(field: &[f32], threshold: f32) -> FieldStats {
     }
 }
 
+/// Spatial scale of a field: how its variation is distributed over lengths.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpatialScale {
+    /// Radially averaged power spectrum: mean power per Fourier mode in each
+    /// integer wavenumber ring `k` (cycles across the shorter side), as a
+    /// fraction of the field's total power. Index 0 (the mean) is 0.
+    pub spectrum: Vec<f32>,
+    /// Radially averaged two-point correlation of the field's fluctuations at
+    /// each integer distance `r`, with `correlation[0] = 1`. Empty for a
+    /// flat field.
+    pub correlation: Vec<f32>,
+    /// Wavelength, in cells, of the spectral peak; `None` for a flat field.
+    /// Stripes or a spot lattice of spacing λ peak at λ; isolated structure
+    /// peaks at the scale of the world.
+    pub dominant_wavelength: Option<f32>,
+    /// Share of the total power in the peak's ring: near 1 for a clean
+    /// periodic pattern, small for isolated spots or noise.
+    pub peak_share: f32,
+    /// Distance, in cells, at which the correlation first falls below `1/e`
+    /// (interpolated); 0 for a flat field, half the shorter side if never.
+    pub correlation_length: f32,
+}
+
+/// [`SpatialScale`] of a `w × h` toroidal field — matter, energy or detritus
+/// alike. The spectrum comes from a 2-D discrete Fourier transform of the
+/// field's fluctuations, the correlation from its inverse transform
+/// (Wiener–Khinchin), both averaged over rings up to half the shorter side.
+pub fn spatial_scale(field: &[f32], w: usize, h: usize) -> SpatialScale {
+    let n = w * h;
+    debug_assert_eq!(field.len(), n);
+    if n == 0 {
+        return SpatialScale::default();
+    }
+    let mean = total(field) / n as f64;
+    let mut re: Vec<f64> = field.iter().map(|&v| v as f64 - mean).collect();
+    let mut im = vec![0.0f64; n];
+    dft2(&mut re, &mut im, w, h, false);
+    let power: Vec<f64> = re.iter().zip(&im).map(|(&a, &b)| a * a + b * b).collect();
+    let total_power: f64 = power.iter().sum();
+
+    let side = w.min(h);
+    let half = side / 2;
+    let signed = |i: usize, len: usize| if i > len / 2 { i as f64 - len as f64 } else { i as f64 };
+    // Ring of each mode (wavenumbers rescaled to the shorter side) or offset.
+    let ring = |x: usize, y: usize, scale: bool| {
+        let (mut fx, mut fy) = (signed(x, w), signed(y, h));
+        if scale {
+            fx *= side as f64 / w as f64;
+            fy *= side as f64 / h as f64;
+        }
+        (fx * fx + fy * fy).sqrt().round() as usize
+    };
+    if total_power <= 1e-12 * n as f64 {
+        return SpatialScale { spectrum: vec![0.0; half + 1], ..SpatialScale::default() };
+    }
+
+    let mut ring_power = vec![0.0f64; half + 1];
+    let mut modes = vec![0usize; half + 1];
+    for y in 0..h {
+        for x in 0..w {
+            let k = ring(x, y, true);
+            if k <= half {
+                ring_power[k] += power[y * w + x];
+                modes[k] += 1;
+            }
+        }
+    }
+    let spectrum: Vec<f32> = ring_power
+        .iter()
+        .zip(&modes)
+        .enumerate()
+        .map(|(k, (&p, &m))| {
+            if k == 0 || m == 0 {
+                0.0
+            } else {
+                (p / m as f64 / total_power) as f32
+            }
+        })
+        .collect();
+    let peak = (1..spectrum.len()).max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b]));
+
+    // Autocovariance: inverse transform of the power spectrum.
+    let mut re = power;
+    let mut im = vec![0.0f64; n];
+    dft2(&mut re, &mut im, w, h, true);
+    let mut sum = vec![0.0f64; half + 1];
+    let mut count = vec![0usize; half + 1];
+    for y in 0..h {
+        for x in 0..w {
+            let r = ring(x, y, false);
+            if r <= half {
+                sum[r] += re[y * w + x];
+                count[r] += 1;
+            }
+        }
+    }
+    let c0 = re[0];
+    let correlation: Vec<f32> =
+        sum.iter().zip(&count).map(|(&s, &c)| (s / c.max(1) as f64 / c0) as f32).collect();
+    let e = (-1.0f32).exp();
+    let correlation_length = correlation
+        .windows(2)
+        .position(|p| p[1] < e)
+        .map_or(half as f32, |r| {
+            let (a, b) = (correlation[r], correlation[r + 1]);
+            r as f32 + (a - e) / (a - b)
+        });
+
+    SpatialScale {
+        dominant_wavelength: peak.map(|k| side as f32 / k as f32),
+        peak_share: peak.map_or(0.0, |k| (ring_power[k] / total_power) as f32),
+        spectrum,
+        correlation,
+        correlation_length,
+    }
+}
+
+/// In-place 2-D discrete Fourier transform of a row-major `w × h` complex
+/// field, unnormalized, applied separably along rows then columns.
+fn dft2(re: &mut [f64], im: &mut [f64], w: usize, h: usize, inverse: bool) {
+    let rows = Dft::new(w);
+    for (r, i) in re.chunks_mut(w).zip(im.chunks_mut(w)) {
+        rows.apply(r, i, inverse);
+    }
+    let cols = Dft::new(h);
+    let (mut cr, mut ci) = (vec![0.0f64; h], vec![0.0f64; h]);
+    for x in 0..w {
+        for y in 0..h {
+            (cr[y], ci[y]) = (re[y * w + x], im[y * w + x]);
+        }
+        cols.apply(&mut cr, &mut ci, inverse);
+        for y in 0..h {
+            (re[y * w + x], im[y * w + x]) = (cr[y], ci[y]);
+        }
+    }
+}
+
+/// Discrete Fourier transform of one line length, with its twiddles computed
+/// once: a radix-2 FFT for powers of two, Bluestein's chirp-z otherwise (a
+/// convolution through a padded power-of-two FFT), so every length — 48
+/// as much as 64 — costs `O(n log n)`.
+struct Dft {
+    n: usize,
+    /// `e^{-2πik/m}` for `k < m/2`, `m` the radix-2 length.
+    twiddle: Vec<(f64, f64)>,
+    bluestein: Option<Bluestein>,
+}
+
+struct Bluestein {
+    /// Padded power-of-two length, at least `2n - 1`.
+    m: usize,
+    /// `e^{-πik²/n}` for `k < n`.
+    chirp: Vec<(f64, f64)>,
+    /// Forward transform of the conjugate chirp, wrapped around to length `m`.
+    filter: (Vec<f64>, Vec<f64>),
+}
+
+impl Dft {
+    fn new(n: usize) -> Self {
+        let pow2 = n < 2 || n.is_power_of_two();
+        let m = if pow2 { n } else { (2 * n - 1).next_power_of_two() };
+        let twiddle = (0..m / 2)
+            .map(|k| {
+                let (s, c) = (-std::f64::consts::TAU * k as f64 / m as f64).sin_cos();
+                (c, s)
+            })
+            .collect();
+        let mut dft = Dft { n, twiddle, bluestein: None };
+        if !pow2 {
+            // k² mod 2n keeps the chirp's phase exact for long lines.
+            let chirp: Vec<(f64, f64)> = (0..n)
+                .map(|k| {
+                    let (s, c) = (-std::f64::consts::PI * ((k * k) % (2 * n)) as f64 / n as f64)
+                        .sin_cos();
+                    (c, s)
+                })
+                .collect();
+            let (mut fr, mut fi) = (vec![0.0f64; m], vec![0.0f64; m]);
+            for (k, &(c, s)) in chirp.iter().enumerate() {
+                (fr[k], fi[k]) = (c, -s);
+                (fr[(m - k) % m], fi[(m - k) % m]) = (c, -s);
+            }
+            dft.radix2(&mut fr, &mut fi);
+            dft.bluestein = Some(Bluestein { m, chirp, filter: (fr, fi) });
+        }
+        dft
+    }
+
+    /// In-place transform of one line, unnormalized. The inverse is the
+    /// forward transform conjugated on the way in and out.
+    fn apply(&self, re: &mut [f64], im: &mut [f64], inverse: bool) {
+        debug_assert_eq!((re.len(), im.len()), (self.n, self.n));
+        let conjugate = |im: &mut [f64]| im.iter_mut().for_each(|v| *v = -*v);
+        if inverse {
+            conjugate(im);
+        }
+        match &self.bluestein {
+            None => self.radix2(re, im),
+            Some(b) => self.chirp_z(b, re, im),
+        }
+        if inverse {
+            conjugate(im);
+        }
+    }
+
+    /// `X_k = c_k Σ_t (x_t c_t) c̄_{k-t}` with `c_k = e^{-πik²/n}`: the sum is
+    /// a circular convolution, done as a product of length-`m` transforms.
+    fn chirp_z(&self, b: &Bluestein, re: &mut [f64], im: &mut [f64]) {
+        let m = b.m;
+        let (mut ar, mut ai) = (vec![0.0f64; m], vec![0.0f64; m]);
+        for (k, &(c, s)) in b.chirp.iter().enumerate() {
+            (ar[k], ai[k]) = (re[k] * c - im[k] * s, re[k] * s + im[k] * c);
+        }
+        self.radix2(&mut ar, &mut ai);
+        for (k, (r, i)) in ar.iter_mut().zip(ai.iter_mut()).enumerate() {
+            let (fr, fi) = (b.filter.0[k], b.filter.1[k]);
+            // Conjugated, so the forward pass below runs the inverse.
+            (*r, *i) = (*r * fr - *i * fi, -(*r * fi + *i * fr));
+        }
+        self.radix2(&mut ar, &mut ai);
+        for (k, &(c, s)) in b.chirp.iter().enumerate() {
+            let (vr, vi) = (ar[k] / m as f64, -ai[k] / m as f64);
+            (re[k], im[k]) = (vr * c - vi * s, vr * s + vi * c);
+        }
+    }
+
+    /// Forward radix-2 FFT of a line as long as the twiddle table's length.
+    fn radix2(&self, re: &mut [f64], im: &mut [f64]) {
+        let n = re.len();
+        debug_assert_eq!(n / 2, self.twiddle.len());
+        // Bit-reversal permutation, then butterflies.
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+        let mut len = 2;
+        while len <= n {
+            let stride = n / len;
+            for start in (0..n).step_by(len) {
+                for k in 0..len / 2 {
+                    let (c, s) = self.twiddle[k * stride];
+                    let (a, b) = (start + k, start + k + len / 2);
+                    let (xr, xi) = (re[b] * c - im[b] * s, re[b] * s + im[b] * c);
+                    (re[b], im[b]) = (re[a] - xr, im[a] - xi);
+                    re[a] += xr;
+                    im[a] += xi;
+                }
+            }
+            len <<= 1;
+        }
+    }
+}
+
 /// One connected blob of above-threshold matter.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Blob {
@@ -181,10 +474,21 @@ impl Components {
 /// 8-connectivity (union-find), then reduce each blob to cell count, mass, and
 /// a toroidal circular-mean centroid.
 pub fn connected_components(field: &[f32], w: usize, h: usize, threshold: f32) -> Components {
+    label_components(field, w, h, threshold).0
+}
+
+/// [`connected_components`], plus the blob each cell belongs to: the index
+/// into `Components::blobs`, or `None` for unoccupied cells.
+pub fn label_components(
+    field: &[f32],
+    w: usize,
+    h: usize,
+    threshold: f32,
+) -> (Components, Vec<Option<usize>>) {
     let n = w * h;
     debug_assert_eq!(field.len(), n);
     if n == 0 {
-        return Components::default();
+        return (Components::default(), Vec::new());
     }
     let occupied: Vec<bool> = field.iter().map(|&v| v > threshold).collect();
 
@@ -265,22 +569,256 @@ pub fn connected_components(field: &[f32], w: usize, h: usize, threshold: f32) -
         }
     }
 
-    let mut blobs: Vec<Blob> = groups
-        .into_values()
-        .map(|a| {
+    let mut blobs: Vec<(usize, Blob)> = groups
+        .into_iter()
+        .map(|(root, a)| {
             let cx = a.xs.atan2(a.xc).rem_euclid(tau) / tau * w as f64;
             let cy = a.ys.atan2(a.yc).rem_euclid(tau) / tau * h as f64;
-            Blob {
+            let blob = Blob {
                 cells: a.cells,
                 mass: a.mass as f32,
                 cx: cx as f32,
                 cy: cy as f32,
-            }
+            };
+            (root, blob)
         })
         .collect();
     // Deterministic order: largest mass first.
-    blobs.sort_by(|a, b| b.mass.partial_cmp(&a.mass).unwrap_or(std::cmp::Ordering::Equal));
-    Components { blobs }
+    blobs.sort_by(|a, b| b.1.mass.partial_cmp(&a.1.mass).unwrap_or(std::cmp::Ordering::Equal));
+    let index: HashMap<usize, usize> =
+        blobs.iter().enumerate().map(|(k, &(root, _))| (root, k)).collect();
+    let labels = (0..n)
+        .map(|i| if occupied[i] { Some(index[&find(&mut parent, i)]) } else { None })
+        .collect();
+    (Components { blobs: blobs.into_iter().map(|(_, b)| b).collect() }, labels)
+}
+
+/// Highest rotational-symmetry order [`blob_shapes`] looks for.
+pub const MAX_SYMMETRY: usize = 8;
+
+/// A blob whose angular harmonics all stay below this fraction of its mass is
+/// isotropic (symmetry order 0).
+pub const SYMMETRY_FLOOR: f32 = 0.1;
+
+/// Share of a blob's angular power that the harmonics of order `n`, `2n`, …
+/// must hold for it to count as `n`-fold symmetric.
+pub const SYMMETRY_SHARE: f32 = 0.8;
+
+/// Morphology of one blob, beyond what [`Blob`] records.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape {
+    /// Angle of the principal axis of the mass, in radians in `(−π/2, π/2]`.
+    pub orientation: f32,
+    /// Major over minor radius of gyration (≥ 1): 1 for a disk or a ring.
+    pub elongation: f32,
+    /// `4π·area / perimeter²` over the occupied cells, the perimeter counted
+    /// in exposed cell edges. Highest for a solid round blob, though a digital
+    /// disk's staircase edge holds it below π²/16 ≈ 0.62; thin, ragged or
+    /// hollow shapes score lower.
+    pub compactness: f32,
+    /// Enclosed pockets of unoccupied cells.
+    pub holes: usize,
+    /// Order of rotational symmetry about the centroid, up to
+    /// [`MAX_SYMMETRY`]: the largest `n` whose multiples hold
+    /// [`SYMMETRY_SHARE`] of the angular Fourier power of the mass. 1 for a
+    /// lopsided crescent, 2 for a dumbbell, 3 for a trefoil; 0 for an
+    /// isotropic disk or ring (see [`SYMMETRY_FLOOR`]).
+    pub symmetry: usize,
+    /// Magnitude of the strongest angular harmonic, as a fraction of the
+    /// mass: 0 for an isotropic blob.
+    pub anisotropy: f32,
+    /// Mean density of the blob's matter in unit-width rings about the
+    /// centroid, innermost first.
+    pub radial_profile: Vec<f32>,
+}
+
+impl Shape {
+    /// Euler number of the blob: one component minus its holes.
+    pub fn euler(&self) -> i64 {
+        1 - self.holes as i64
+    }
+}
+
+/// The [`Shape`] of every blob of `comps`, in the same order, from the cell
+/// `labels` of [`label_components`].
+pub fn blob_shapes(
+    field: &[f32],
+    w: usize,
+    h: usize,
+    comps: &Components,
+    labels: &[Option<usize>],
+) -> Vec<Shape> {
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); comps.count()];
+    for (i, l) in labels.iter().enumerate() {
+        if let Some(k) = *l {
+            members[k].push(i);
+        }
+    }
+    comps
+        .blobs
+        .iter()
+        .zip(&members)
+        .enumerate()
+        .map(|(k, (blob, cells))| blob_shape(field, w, h, blob, k, cells, labels))
+        .collect()
+}
+
+fn blob_shape(
+    field: &[f32],
+    w: usize,
+    h: usize,
+    blob: &Blob,
+    k: usize,
+    cells: &[usize],
+    labels: &[Option<usize>],
+) -> Shape {
+    // Offsets from the centroid, unwrapped round the torus.
+    let offsets: Vec<(f32, f32, f64)> = cells
+        .iter()
+        .map(|&i| {
+            let dx = wrap_delta((i % w) as f32 - blob.cx, w as f32);
+            let dy = wrap_delta((i / w) as f32 - blob.cy, h as f32);
+            (dx, dy, field[i].max(0.0) as f64)
+        })
+        .collect();
+    let mass: f64 = offsets.iter().map(|p| p.2).sum::<f64>().max(f64::MIN_POSITIVE);
+
+    // Second moments: principal axis and elongation.
+    let (mut sxx, mut syy, mut sxy) = (0.0f64, 0.0f64, 0.0f64);
+    for &(dx, dy, m) in &offsets {
+        let (dx, dy) = (dx as f64, dy as f64);
+        sxx += m * dx * dx;
+        syy += m * dy * dy;
+        sxy += m * dx * dy;
+    }
+    let (sxx, syy, sxy) = (sxx / mass, syy / mass, sxy / mass);
+    let orientation = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    let mean = 0.5 * (sxx + syy);
+    let spread = (0.25 * (sxx - syy) * (sxx - syy) + sxy * sxy).sqrt();
+    let elongation = ((mean + spread) / (mean - spread).max(1e-12)).sqrt();
+
+    // Perimeter: cell edges facing anything but this blob.
+    let ours = |x: usize, y: usize| labels[y * w + x] == Some(k);
+    let mut perimeter = 0usize;
+    for &i in cells {
+        let (x, y) = (i % w, i / w);
+        let neighbors = [
+            ((x + 1) % w, y),
+            ((x + w - 1) % w, y),
+            (x, (y + 1) % h),
+            (x, (y + h - 1) % h),
+        ];
+        perimeter += neighbors.iter().filter(|&&(nx, ny)| !ours(nx, ny)).count();
+    }
+    let area = cells.len() as f32;
+    let compactness = 4.0 * std::f32::consts::PI * area / (perimeter.max(1) as f32).powi(2);
+
+    // Angular harmonics of the mass about the centroid.
+    let mut harmonics = [(0.0f64, 0.0f64); MAX_SYMMETRY];
+    for &(dx, dy, m) in &offsets {
+        if dx * dx + dy * dy < 0.25 {
+            continue;
+        }
+        let theta = (dy as f64).atan2(dx as f64);
+        for (n, (re, im)) in harmonics.iter_mut().enumerate() {
+            let a = (n + 1) as f64 * theta;
+            *re += m * a.cos();
+            *im += m * a.sin();
+        }
+    }
+    let power: Vec<f64> = harmonics.iter().map(|&(re, im)| re * re + im * im).collect();
+    let total: f64 = power.iter().sum();
+    let anisotropy = (power.iter().fold(0.0f64, |a, &p| a.max(p)).sqrt() / mass) as f32;
+    let symmetry = if anisotropy < SYMMETRY_FLOOR {
+        0
+    } else {
+        (1..=MAX_SYMMETRY)
+            .rev()
+            .find(|&n| {
+                let share: f64 = power.iter().skip(n - 1).step_by(n).sum();
+                share >= SYMMETRY_SHARE as f64 * total
+            })
+            .unwrap_or(1)
+    };
+
+    // Radial profile: mass per unit ring, over the ring's area.
+    let mut radial_profile = Vec::new();
+    for &(dx, dy, m) in &offsets {
+        let r = (dx * dx + dy * dy).sqrt() as usize;
+        if r >= radial_profile.len() {
+            radial_profile.resize(r + 1, 0.0f32);
+        }
+        radial_profile[r] += m as f32;
+    }
+    for (r, v) in radial_profile.iter_mut().enumerate() {
+        *v /= std::f32::consts::PI * (2 * r + 1) as f32;
+    }
+
+    Shape {
+        orientation: orientation as f32,
+        elongation: elongation as f32,
+        compactness,
+        holes: count_holes(w, h, blob, cells),
+        symmetry,
+        anisotropy,
+        radial_profile,
+    }
+}
+
+/// Pockets of background enclosed by a blob: 4-connected regions of its
+/// bounding box, padded by one cell, that do not reach the box's edge
+/// (4-connected background is the dual of the blobs' 8-connectivity).
+fn count_holes(w: usize, h: usize, blob: &Blob, cells: &[usize]) -> usize {
+    let (ox, oy) = (blob.cx.round() as i64, blob.cy.round() as i64);
+    let rel: Vec<(i64, i64)> = cells
+        .iter()
+        .map(|&i| {
+            let dx = ((i % w) as i64 - ox).rem_euclid(w as i64);
+            let dy = ((i / w) as i64 - oy).rem_euclid(h as i64);
+            let unwrap = |d: i64, n: i64| if d >= (n + 1) / 2 { d - n } else { d };
+            (unwrap(dx, w as i64), unwrap(dy, h as i64))
+        })
+        .collect();
+    let Some(x0) = rel.iter().map(|p| p.0).min() else {
+        return 0;
+    };
+    let x1 = rel.iter().map(|p| p.0).max().unwrap_or(x0);
+    let y0 = rel.iter().map(|p| p.1).min().unwrap_or(0);
+    let y1 = rel.iter().map(|p| p.1).max().unwrap_or(y0);
+    let (bw, bh) = ((x1 - x0 + 3) as usize, (y1 - y0 + 3) as usize);
+    let mut solid = vec![false; bw * bh];
+    for &(x, y) in &rel {
+        solid[(y - y0 + 1) as usize * bw + (x - x0 + 1) as usize] = true;
+    }
+
+    let mut seen = solid.clone();
+    let mut holes = 0;
+    for start in 0..bw * bh {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut stack = vec![start];
+        let mut enclosed = true;
+        while let Some(i) = stack.pop() {
+            let (x, y) = (i % bw, i / bw);
+            enclosed &= x > 0 && y > 0 && x + 1 < bw && y + 1 < bh;
+            let neighbors = [
+                (x > 0).then(|| i - 1),
+                (x + 1 < bw).then(|| i + 1),
+                (y > 0).then(|| i - bw),
+                (y + 1 < bh).then(|| i + bw),
+            ];
+            for j in neighbors.into_iter().flatten() {
+                if !seen[j] {
+                    seen[j] = true;
+                    stack.push(j);
+                }
+            }
+        }
+        holes += enclosed as usize;
+    }
+    holes
 }
 
 /// Mean absolute per-cell change between two field snapshots — the activity /
@@ -310,7 +848,9 @@ pub struct VelocityStats {
 
 /// Tracks blobs across frames to recover a velocity distribution. Greedy
 /// nearest-centroid matching under toroidal distance, gated by `max_match_dist`
-/// so a vanished blob is not spuriously matched to a distant new one.
+/// so a vanished blob is not spuriously matched to a distant new one. For
+/// persistent identities, trajectories and lineages see
+/// [`tracking::BlobTracker`](crate::tracking::BlobTracker).
 pub struct Tracker {
     w: f32,
     h: f32,
@@ -377,9 +917,626 @@ fn wrap_delta(mut d: f32, size: f32) -> f32 {
     d
 }
 
+/// Tolerances for clustering the localized genome (M-γ-1) into species.
+///
+/// Occupied cells are binned in `(μ, σ)` genome space at `mu_tol × sigma_tol`
+/// resolution. A bin holding at least a `min_density` fraction of all occupied
+/// mass is a *core* bin; core bins that touch (8-neighborhood in genome space)
+/// form one species, and sparse bins attach to an adjacent core species or are
+/// discarded as noise — the grid form of DBSCAN. Requiring density keeps a
+/// thin trail of blended interface cells from chaining two distinct species
+/// into one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeciesConfig {
+    /// Genome-space bin width along `μ`.
+    pub mu_tol: f32,
+    /// Genome-space bin width along `σ`.
+    pub sigma_tol: f32,
+    /// Fraction of total occupied mass a bin must hold to be a core bin.
+    pub min_density: f32,
+    /// Species lighter than this in total are dropped as noise.
+    pub min_mass: f32,
+    /// Max genome-centroid distance, in bin widths, for a species to keep its
+    /// identity from one sample to the next.
+    pub match_dist: f32,
+}
+
+impl Default for SpeciesConfig {
+    fn default() -> Self {
+        SpeciesConfig {
+            mu_tol: 0.005,
+            sigma_tol: 0.002,
+            min_density: 0.005,
+            min_mass: 1.0,
+            match_dist: 2.0,
+        }
+    }
+}
+
+/// One species: a cluster of occupied cells sharing a genome.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Species {
+    /// Identity. Index within the census until a [`SpeciesTracker`] assigns a
+    /// persistent ID.
+    pub id: u32,
+    /// Mass-weighted genome centroid.
+    pub mu: f32,
+    pub sigma: f32,
+    /// Total mass of the species (its abundance).
+    pub mass: f32,
+    /// Number of occupied cells.
+    pub cells: usize,
+    /// Number of spatially connected blobs the species occupies.
+    pub blobs: usize,
+    /// Mass-weighted spatial centroid (toroidal circular mean), in cells.
+    pub cx: f32,
+    pub cy: f32,
+    /// Spatial extent: mass-weighted RMS toroidal distance from the centroid.
+    pub extent: f32,
+}
+
+/// A species census of one snapshot.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Census {
+    /// Species, heaviest first.
+    pub species: Vec<Species>,
+    /// Occupied mass that belongs to no species (sparse blends, tiny clusters).
+    pub noise_mass: f32,
+}
+
+impl Census {
+    /// Species richness: number of species present.
+    pub fn richness(&self) -> usize {
+        self.species.len()
+    }
+
+    /// Shannon diversity `H = −Σ p ln p` over species mass fractions, in nats.
+    pub fn shannon(&self) -> f32 {
+        let total: f64 = self.species.iter().map(|s| s.mass as f64).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let mut h = 0.0f64;
+        for s in &self.species {
+            let p = s.mass as f64 / total;
+            if p > 0.0 {
+                h -= p * p.ln();
+            }
+        }
+        h as f32
+    }
+
+    /// Gini–Simpson diversity `1 − Σ p²`: the chance two random units of mass
+    /// belong to different species.
+    pub fn simpson(&self) -> f32 {
+        let total: f64 = self.species.iter().map(|s| s.mass as f64).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let sum_p2: f64 = self
+            .species
+            .iter()
+            .map(|s| {
+                let p = s.mass as f64 / total;
+                p * p
+            })
+            .sum();
+        (1.0 - sum_p2) as f32
+    }
+}
+
+/// Cluster the occupied genome field into species (see [`SpeciesConfig`]) and
+/// reduce each to mass, cell and blob count, centroid, and spatial extent.
+/// `mass`, `mu` and `sigma` are row-major `w × h` fields; cells at or below
+/// `threshold` are unoccupied.
+pub fn species_census(
+    mass: &[f32],
+    mu: &[f32],
+    sigma: &[f32],
+    w: usize,
+    h: usize,
+    threshold: f32,
+    cfg: &SpeciesConfig,
+) -> Census {
+    use std::collections::HashMap;
+    let n = w * h;
+    debug_assert!(mass.len() == n && mu.len() == n && sigma.len() == n);
+
+    // Bin occupied cells in genome space.
+    let key = |i: usize| -> (i32, i32) {
+        (
+            (mu[i] / cfg.mu_tol).floor() as i32,
+            (sigma[i] / cfg.sigma_tol).floor() as i32,
+        )
+    };
+    let mut bins: HashMap<(i32, i32), f64> = HashMap::new();
+    let mut occupied_mass = 0.0f64;
+    for (i, &m) in mass.iter().enumerate() {
+        if m > threshold {
+            *bins.entry(key(i)).or_insert(0.0) += m as f64;
+            occupied_mass += m as f64;
+        }
+    }
+    let core_mass = cfg.min_density as f64 * occupied_mass;
+
+    // Label core bins by flood fill over genome-space 8-neighbors. Sorting the
+    // keys keeps labels deterministic regardless of hash order.
+    let mut keys: Vec<(i32, i32)> = bins.keys().copied().collect();
+    keys.sort_unstable();
+    let is_core = |k: &(i32, i32)| bins.get(k).is_some_and(|&m| m >= core_mass);
+    let mut label: HashMap<(i32, i32), usize> = HashMap::new();
+    let mut n_clusters = 0;
+    for k in &keys {
+        if !is_core(k) || label.contains_key(k) {
+            continue;
+        }
+        let mut stack = vec![*k];
+        label.insert(*k, n_clusters);
+        while let Some((a, b)) = stack.pop() {
+            for da in -1..=1 {
+                for db in -1..=1 {
+                    let nk = (a + da, b + db);
+                    if is_core(&nk) && !label.contains_key(&nk) {
+                        label.insert(nk, n_clusters);
+                        stack.push(nk);
+                    }
+                }
+            }
+        }
+        n_clusters += 1;
+    }
+    // Border bins join the heaviest adjacent core bin's cluster; the rest is noise.
+    for k in &keys {
+        if is_core(k) {
+            continue;
+        }
+        let mut best: Option<(f64, usize)> = None;
+        for da in -1..=1 {
+            for db in -1..=1 {
+                let nk = (k.0 + da, k.1 + db);
+                if let (true, Some(&c)) = (is_core(&nk), label.get(&nk)) {
+                    let m = bins[&nk];
+                    if best.is_none_or(|(bm, _)| m > bm) {
+                        best = Some((m, c));
+                    }
+                }
+            }
+        }
+        if let Some((_, c)) = best {
+            label.insert(*k, c);
+        }
+    }
+
+    // Per-cell cluster assignment, then per-cluster reductions.
+    let assign: Vec<Option<usize>> = (0..n)
+        .map(|i| {
+            if mass[i] > threshold {
+                label.get(&key(i)).copied()
+            } else {
+                None
+            }
+        })
+        .collect();
+    let tau = std::f64::consts::TAU;
+    let mut census = Census::default();
+    let mut masked = vec![0.0f32; n];
+    for c in 0..n_clusters {
+        let (mut m, mut gmu, mut gsig, mut cells) = (0.0f64, 0.0f64, 0.0f64, 0usize);
+        let (mut xc, mut xs, mut yc, mut ys) = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+        for (i, a) in assign.iter().enumerate() {
+            if *a != Some(c) {
+                masked[i] = 0.0;
+                continue;
+            }
+            masked[i] = mass[i];
+            let v = mass[i] as f64;
+            let (x, y) = ((i % w) as f64, (i / w) as f64);
+            m += v;
+            gmu += v * mu[i] as f64;
+            gsig += v * sigma[i] as f64;
+            cells += 1;
+            xc += v * (tau * x / w as f64).cos();
+            xs += v * (tau * x / w as f64).sin();
+            yc += v * (tau * y / h as f64).cos();
+            ys += v * (tau * y / h as f64).sin();
+        }
+        if m < cfg.min_mass as f64 {
+            census.noise_mass += m as f32;
+            continue;
+        }
+        let cx = (xs.atan2(xc).rem_euclid(tau) / tau * w as f64) as f32;
+        let cy = (ys.atan2(yc).rem_euclid(tau) / tau * h as f64) as f32;
+        let mut spread = 0.0f64;
+        for (i, &v) in masked.iter().enumerate() {
+            if v > 0.0 {
+                let dx = wrap_delta((i % w) as f32 - cx, w as f32);
+                let dy = wrap_delta((i / w) as f32 - cy, h as f32);
+                spread += v as f64 * (dx * dx + dy * dy) as f64;
+            }
+        }
+        census.species.push(Species {
+            id: 0,
+            mu: (gmu / m) as f32,
+            sigma: (gsig / m) as f32,
+            mass: m as f32,
+            cells,
+            blobs: connected_components(&masked, w, h, threshold).count(),
+            cx,
+            cy,
+            extent: (spread / m).sqrt() as f32,
+        });
+    }
+    for (i, &v) in mass.iter().enumerate() {
+        if v > threshold && assign[i].is_none() {
+            census.noise_mass += v;
+        }
+    }
+    census
+        .species
+        .sort_by(|a, b| b.mass.partial_cmp(&a.mass).unwrap_or(std::cmp::Ordering::Equal));
+    for (i, s) in census.species.iter_mut().enumerate() {
+        s.id = i as u32;
+    }
+    census
+}
+
+/// Carries species identities across samples so abundance can be read as a
+/// time series. Each census species is matched one-to-one to the nearest
+/// previous species in genome space (closest pairs first, within
+/// `SpeciesConfig::match_dist` bin widths). Unmatched newcomers get fresh IDs
+/// (originations); previous species left unmatched went extinct.
+pub struct SpeciesTracker {
+    cfg: SpeciesConfig,
+    prev: Vec<Species>,
+    next_id: u32,
+    /// Whether a baseline census has been observed yet.
+    primed: bool,
+    /// Species that vanished between consecutive samples.
+    pub extinctions: usize,
+    /// Species that appeared after the first sample.
+    pub originations: usize,
+}
+
+impl SpeciesTracker {
+    pub fn new(cfg: SpeciesConfig) -> Self {
+        SpeciesTracker {
+            cfg,
+            prev: Vec::new(),
+            next_id: 0,
+            primed: false,
+            extinctions: 0,
+            originations: 0,
+        }
+    }
+
+    /// Genome distance between two species, in bin widths.
+    fn dist(&self, a: &Species, b: &Species) -> f32 {
+        let dm = (a.mu - b.mu) / self.cfg.mu_tol;
+        let ds = (a.sigma - b.sigma) / self.cfg.sigma_tol;
+        (dm * dm + ds * ds).sqrt()
+    }
+
+    /// Rewrite the census' species IDs to persistent ones and update the
+    /// extinction/origination counts.
+    pub fn observe(&mut self, census: &mut Census) {
+        let mut pairs: Vec<(f32, usize, usize)> = Vec::new();
+        for (ci, cur) in census.species.iter().enumerate() {
+            for (pi, p) in self.prev.iter().enumerate() {
+                let d = self.dist(cur, p);
+                if d <= self.cfg.match_dist {
+                    pairs.push((d, ci, pi));
+                }
+            }
+        }
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut cur_id: Vec<Option<u32>> = vec![None; census.species.len()];
+        let mut prev_taken = vec![false; self.prev.len()];
+        for (_, ci, pi) in pairs {
+            if cur_id[ci].is_none() && !prev_taken[pi] {
+                cur_id[ci] = Some(self.prev[pi].id);
+                prev_taken[pi] = true;
+            }
+        }
+        self.extinctions += prev_taken.iter().filter(|&&t| !t).count();
+        for (s, id) in census.species.iter_mut().zip(cur_id) {
+            s.id = match id {
+                Some(id) => id,
+                None => {
+                    if self.primed {
+                        self.originations += 1;
+                    }
+                    self.next_id += 1;
+                    self.next_id - 1
+                }
+            };
+        }
+        self.primed = true;
+        self.prev = census.species.clone();
+    }
+}
+
+/// Abundance time series from a run's samples: for every species ID ever seen,
+/// its mass at each sample (0 where absent). Sorted by ID.
+pub fn abundance_series(samples: &[Sample]) -> Vec<(u32, Vec<f32>)> {
+    let mut ids: Vec<u32> = samples
+        .iter()
+        .flat_map(|s| s.species.iter().map(|sp| sp.id))
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter()
+        .map(|id| {
+            let series = samples
+                .iter()
+                .map(|s| {
+                    s.species
+                        .iter()
+                        .find(|sp| sp.id == id)
+                        .map_or(0.0, |sp| sp.mass)
+                })
+                .collect();
+            (id, series)
+        })
+        .collect()
+}
+
+/// Discretize the occupied genome field into Bedau–Packard components: one
+/// per `(μ, σ)` bin of `cfg`'s tolerances, keyed by the packed bin indices,
+/// with the occupied mass it holds. Sorted by key.
+pub fn genome_components(
+    mass: &[f32],
+    mu: &[f32],
+    sigma: &[f32],
+    threshold: f32,
+    cfg: &SpeciesConfig,
+) -> Vec<(u64, f32)> {
+    let mut bins = std::collections::BTreeMap::new();
+    for ((&m, &u), &s) in mass.iter().zip(mu).zip(sigma) {
+        if m > threshold {
+            let (bu, bs) = ((u / cfg.mu_tol).floor() as i32, (s / cfg.sigma_tol).floor() as i32);
+            let key = ((bu as u32 as u64) << 32) | bs as u32 as u64;
+            *bins.entry(key).or_insert(0.0f32) += m;
+        }
+    }
+    bins.into_iter().collect()
+}
+
+/// Settings for Bedau–Packard evolutionary activity statistics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActivityConfig {
+    /// Quantile of the neutral shadow's activity distribution that sets the
+    /// significance threshold `a*`.
+    pub significance: f32,
+    /// How many times the neutral share of above-`a*` activity values a run
+    /// must show for its new activity to count as positive.
+    pub min_excess: f32,
+    /// Seed of the shadow's shuffles.
+    pub seed: u64,
+}
+
+impl Default for ActivityConfig {
+    fn default() -> Self {
+        ActivityConfig { significance: 0.95, min_excess: 2.0, seed: 0 }
+    }
+}
+
+/// Bedau's classes of evolutionary dynamics, read off diversity `D`, new
+/// activity and mean cumulative new activity. "Unbounded" can only mean
+/// "still growing at the end" in a finite run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum EvolutionaryClass {
+    /// Class 1: bounded diversity, no new activity.
+    None,
+    /// Class 2: diversity keeps growing, but neutrally — no new activity.
+    UnboundedDiversity,
+    /// Class 3: positive new activity, bounded.
+    Bounded,
+    /// Class 4: positive new activity, with diversity and mean cumulative new
+    /// activity both still growing.
+    Unbounded,
+}
+
+/// Evolutionary activity at one sample.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ActivityPoint {
+    pub step: usize,
+    /// Diversity `D`: components present.
+    pub diversity: usize,
+    /// Total activity `A = Σ aᵢ` over present components.
+    pub total: f64,
+    /// Mean activity `A / D`.
+    pub mean: f64,
+    /// New activity: summed activity of present components in the window
+    /// `[a*, 2a*)` — those just established as significant. Its rises are the
+    /// waves of new adaptations.
+    pub new: f64,
+    /// New activity summed over the run so far.
+    pub cumulative_new: f64,
+    /// Total activity of the neutral shadow at this sample.
+    pub shadow_total: f64,
+}
+
+/// Bedau–Packard statistics of a run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EvolutionaryActivity {
+    /// One point per observed sample.
+    pub series: Vec<ActivityPoint>,
+    /// Significance threshold `a*` from the neutral shadow.
+    pub threshold: f64,
+    /// Share of the run's activity values above `a*`, over the share the
+    /// neutral shadow would give (`1 − significance`): about 1 when
+    /// persistence is what chance alone produces, large when some components
+    /// persist far beyond it.
+    pub excess: f32,
+    /// Bedau's class, `None` (the `Option`) before any sample.
+    pub class: Option<EvolutionaryClass>,
+}
+
+/// Accumulates Bedau–Packard **evolutionary activity**: every component
+/// (a genome bin from [`genome_components`], or any other heritable key such
+/// as a lineage ID) counts its mass into an activity counter at each sample it
+/// is present, so persistent, abundant components build up activity.
+///
+/// Persistence alone proves nothing, so the meter also runs the neutral
+/// **shadow** model: at every sample the same masses are dealt to the same
+/// present components in a shuffled order, destroying any link between a
+/// component and its success. The shadow's activity distribution sets the
+/// threshold `a*` above which a component's activity is adaptively
+/// significant.
+#[derive(Clone, Debug)]
+pub struct ActivityMeter {
+    cfg: ActivityConfig,
+    frames: Vec<(usize, Vec<(u64, f32)>)>,
+}
+
+impl ActivityMeter {
+    pub fn new(cfg: ActivityConfig) -> Self {
+        ActivityMeter { cfg, frames: Vec::new() }
+    }
+
+    /// Record the components present at `step`, with their masses.
+    pub fn observe(&mut self, step: usize, components: &[(u64, f32)]) {
+        let present = components.iter().filter(|c| c.1 > 0.0).copied().collect();
+        self.frames.push((step, present));
+    }
+
+    /// Discretize `world`'s genome (see [`genome_components`]) and record it;
+    /// a no-op without a genome.
+    pub fn observe_world(
+        &mut self,
+        world: &World,
+        step: usize,
+        threshold: f32,
+        cfg: &SpeciesConfig,
+    ) {
+        if let (Some(mu), Some(sigma)) = (world.mu_field(), world.sigma_field()) {
+            let components = genome_components(&world.mass_field(), mu, sigma, threshold, cfg);
+            self.observe(step, &components);
+        }
+    }
+
+    /// Whether anything has been observed.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Replay the samples into activity statistics.
+    pub fn finish(&self) -> EvolutionaryActivity {
+        use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+        use std::collections::HashMap;
+        if self.frames.is_empty() {
+            return EvolutionaryActivity::default();
+        }
+
+        // The shadow: each sample's masses dealt out in a shuffled order.
+        let mut rng = StdRng::seed_from_u64(self.cfg.seed);
+        let mut shadow: HashMap<u64, f64> = HashMap::new();
+        let mut shadow_values = Vec::new();
+        let mut shadow_totals = Vec::with_capacity(self.frames.len());
+        for (_, present) in &self.frames {
+            let mut masses: Vec<f32> = present.iter().map(|c| c.1).collect();
+            masses.shuffle(&mut rng);
+            let mut total = 0.0;
+            for (&(key, _), m) in present.iter().zip(masses) {
+                let a = shadow.entry(key).or_insert(0.0);
+                *a += m as f64;
+                total += *a;
+                shadow_values.push(*a);
+            }
+            shadow_totals.push(total);
+        }
+        shadow_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let q = (self.cfg.significance as f64 * shadow_values.len() as f64) as usize;
+        let threshold =
+            shadow_values.get(q.min(shadow_values.len() - 1)).copied().unwrap_or(0.0);
+
+        // The real run, measured against `a*`.
+        let mut activity: HashMap<u64, f64> = HashMap::new();
+        let mut series = Vec::with_capacity(self.frames.len());
+        let (mut values, mut above, mut cumulative_new) = (0usize, 0usize, 0.0f64);
+        for ((step, present), shadow_total) in self.frames.iter().zip(shadow_totals) {
+            let (mut total, mut new) = (0.0f64, 0.0f64);
+            for &(key, m) in present {
+                let a = activity.entry(key).or_insert(0.0);
+                *a += m as f64;
+                total += *a;
+                values += 1;
+                if *a > threshold {
+                    above += 1;
+                    if *a < 2.0 * threshold {
+                        new += *a;
+                    }
+                }
+            }
+            cumulative_new += new;
+            let diversity = present.len();
+            series.push(ActivityPoint {
+                step: *step,
+                diversity,
+                total,
+                mean: if diversity > 0 { total / diversity as f64 } else { 0.0 },
+                new,
+                cumulative_new,
+                shadow_total,
+            });
+        }
+        let neutral = (1.0 - self.cfg.significance).max(1e-6);
+        let excess = if values > 0 { above as f32 / values as f32 / neutral } else { 0.0 };
+
+        let positive = excess >= self.cfg.min_excess;
+        let diversity: Vec<f64> = series.iter().map(|p| p.diversity as f64).collect();
+        let mean_new: Vec<f64> = series
+            .iter()
+            .map(|p| if p.diversity > 0 { p.cumulative_new / p.diversity as f64 } else { 0.0 })
+            .collect();
+        let class = match (positive, still_growing(&diversity)) {
+            (false, false) => EvolutionaryClass::None,
+            (false, true) => EvolutionaryClass::UnboundedDiversity,
+            (true, true) if still_growing(&mean_new) => EvolutionaryClass::Unbounded,
+            (true, _) => EvolutionaryClass::Bounded,
+        };
+        EvolutionaryActivity { series, threshold, excess, class: Some(class) }
+    }
+}
+
+/// Whether a series is still rising over its second half: the least-squares
+/// trend across that half adds at least a fifth of the half's mean.
+fn still_growing(series: &[f64]) -> bool {
+    let tail = &series[series.len() / 2..];
+    let n = tail.len() as f64;
+    if tail.len() < 2 {
+        return false;
+    }
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = tail.iter().sum::<f64>() / n;
+    let (mut sxy, mut sxx) = (0.0f64, 0.0f64);
+    for (i, &y) in tail.iter().enumerate() {
+        let dx = i as f64 - mean_x;
+        sxy += dx * (y - mean_y);
+        sxx += dx * dx;
+    }
+    let rise = sxy / sxx * (n - 1.0);
+    mean_y > 0.0 && rise >= 0.2 * mean_y
+}
+
+/// Per-channel mass time series from a run's samples: entry `c` is channel `c`'s
+/// mass at each sample. With trophic links (F5) these are the population curves
+/// of predator and prey.
+pub fn channel_mass_series(samples: &[Sample]) -> Vec<Vec<f64>> {
+    let channels = samples.iter().map(|s| s.channel_mass.len()).max().unwrap_or(0);
+    (0..channels)
+        .map(|c| {
+            samples
+                .iter()
+                .map(|s| s.channel_mass.get(c).copied().unwrap_or(0.0))
+                .collect()
+        })
+        .collect()
+}
+
 /// A behavior fingerprint of a whole run — the axes an outer-loop search (F2)
 /// can illuminate. Every field here is intrinsic (measured, not designed).
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct RunSummary {
     pub steps: usize,
     /// Relative mass drift over the run (conservation check).
@@ -398,12 +1555,116 @@ pub struct RunSummary {
     pub mean_speed: f32,
     /// Largest matched blob speed seen anywhere in the run.
     pub peak_speed: f32,
+    /// Time-averaged species richness (0 unless the genome is enabled).
+    pub mean_richness: f32,
+    /// Species richness at the final sample.
+    pub final_richness: usize,
+    /// Time-averaged Shannon diversity of species abundances, in nats.
+    pub mean_shannon: f32,
+    /// Time-averaged Gini–Simpson diversity of species abundances.
+    pub mean_simpson: f32,
+    /// Species lost between consecutive samples over the whole run.
+    pub extinctions: usize,
+    /// Species that appeared after the first sample.
+    pub originations: usize,
+    /// Bedau–Packard new activity over genome bins, summed over the run (0
+    /// without a genome).
+    #[serde(default)]
+    pub new_activity: f64,
+    /// Bedau's class of the run's evolutionary dynamics (`None` without a
+    /// genome).
+    #[serde(default)]
+    pub evolutionary_class: Option<EvolutionaryClass>,
+    /// Blobs that appeared out of nothing after the first sample.
+    #[serde(default)]
+    pub births: usize,
+    /// Blobs that vanished without merging into another.
+    #[serde(default)]
+    pub deaths: usize,
+    /// Blobs that broke into several.
+    #[serde(default)]
+    pub splits: usize,
+    /// Blobs formed by several fusing.
+    #[serde(default)]
+    pub merges: usize,
+    /// Distinct kinds of narrative event seen (0–4).
+    #[serde(default)]
+    pub event_diversity: usize,
+    /// Mean and variance of blob [`Shape::elongation`], over every sampled
+    /// blob above the narrative noise floor.
+    #[serde(default)]
+    pub mean_elongation: f32,
+    #[serde(default)]
+    pub var_elongation: f32,
+    /// Mean and variance of blob [`Shape::compactness`].
+    #[serde(default)]
+    pub mean_compactness: f32,
+    #[serde(default)]
+    pub var_compactness: f32,
+    /// Mean and variance of blob [`Shape::holes`].
+    #[serde(default)]
+    pub mean_holes: f32,
+    #[serde(default)]
+    pub var_holes: f32,
+    /// Mean and variance of blob [`Shape::symmetry`] order.
+    #[serde(default)]
+    pub mean_symmetry: f32,
+    #[serde(default)]
+    pub var_symmetry: f32,
+    /// Time-averaged dominant wavelength of the matter field, over the
+    /// samples that had one (0 if none did).
+    #[serde(default)]
+    pub mean_wavelength: f32,
+    /// Time-averaged [`SpatialScale::peak_share`] of the matter field.
+    #[serde(default)]
+    pub mean_peak_share: f32,
+    /// Time-averaged correlation length of the matter field.
+    #[serde(default)]
+    pub mean_correlation_length: f32,
+    /// Time-averaged stored energy (0 without the energy economy).
+    #[serde(default)]
+    pub mean_energy: f64,
+    /// Stored energy at the final sample.
+    #[serde(default)]
+    pub final_energy: f64,
+    /// Time-averaged live share of all matter, live plus detritus (0 without
+    /// the detritus cycle).
+    #[serde(default)]
+    pub mean_live_fraction: f32,
+    /// Time-averaged share of all matter dying per step — the turnover rate
+    /// (0 without the detritus cycle).
+    #[serde(default)]
+    pub mean_turnover: f32,
+    /// Time-averaged share of all matter recycled from detritus per step.
+    #[serde(default)]
+    pub mean_recycling: f32,
+    /// Time-averaged [`Sample::genome_diversity`] (0 without a genome).
+    #[serde(default)]
+    pub mean_genome_diversity: f32,
+    /// Time-averaged spacetime [`CompressionComplexity`] of the matter field.
+    #[serde(default)]
+    pub mean_lz_complexity: f32,
+    /// Time-averaged statistical [`CompressionComplexity`] of the matter
+    /// field: high for structure between order and noise.
+    #[serde(default)]
+    pub mean_statistical_complexity: f32,
 }
 
 /// Drives a `World` forward `steps` steps, sampling metrics every `sample_every`
 /// steps, and folds the run into a `RunSummary`. `threshold` sets what counts as
 /// occupied matter for occupancy and blob detection; `max_match_dist` gates blob
-/// matching for velocity. Returns the summary and the per-sample time series.
+/// matching for velocity. If the world carries a genome (M-γ-1), each sample
+/// also takes a species census under the default [`SpeciesConfig`], tracked
+/// across samples for abundance, diversity and extinction counts, and feeds
+/// the genome bins to an [`ActivityMeter`] for Bedau–Packard statistics under
+/// the default [`ActivityConfig`]. Blobs are followed by a [`BlobTracker`]
+/// under the default [`TrackConfig`] (with `max_match_dist`) to count
+/// narrative events; the [`Shape`]s of the same blobs are folded into mean
+/// and variance of each morphology descriptor. With the energy economy,
+/// detritus cycle or genome on, each sample also records stored energy,
+/// detritus and its fluxes, and genome diversity. A [`ComplexityMeter`] under
+/// the default [`CompressionConfig`] follows the matter field's compression
+/// complexity. Returns the summary and the per-sample time series.
 pub fn measure_run(
     world: &mut World,
     steps: usize,
@@ -411,74 +1672,388 @@ pub fn measure_run(
     threshold: f32,
     max_match_dist: f32,
 ) -> (RunSummary, Vec<Sample>) {
-    let sample_every = sample_every.max(1);
-    let initial_mass = world.total_mass();
-    let mut tracker = Tracker::new(world.width(), world.height(), max_match_dist);
-
-    let mut samples: Vec<Sample> = Vec::new();
-    let mut prev_field: Option<Vec<f32>> = None;
-
-    let mut sum_conc = 0.0f64;
-    let mut sum_comp = 0.0f64;
-    let mut sum_act = 0.0f64;
-    let mut sum_speed = 0.0f64;
-    let mut peak_speed = 0.0f32;
-    let mut n_samples = 0.0f64;
-    let mut last = Sample::default();
-
-    // Include step 0 and then every sample_every steps.
-    for step in 0..=steps {
-        if step > 0 {
-            world.step();
-        }
-        if step % sample_every != 0 && step != steps {
-            continue;
+    let mut meter = RunMeter::new(world, steps, threshold, max_match_dist);
+    drive(world, steps, sample_every, &mut [&mut meter]);
+    meter.finish(world)
+}
+
+/// [`measure_run`] over every world of a [`WorldBatch`], stepped in lockstep:
+/// one `(summary, samples)` per world, in batch order, identical to measuring
+/// each world alone.
+pub fn measure_batch(
+    batch: &mut WorldBatch,
+    steps: usize,
+    sample_every: usize,
+    threshold: f32,
+    max_match_dist: f32,
+) -> Vec<(RunSummary, Vec<Sample>)> {
+    let mut meters: Vec<RunMeter> = batch
+        .worlds()
+        .iter()
+        .map(|w| RunMeter::new(w, steps, threshold, max_match_dist))
+        .collect();
+    let mut observers: Vec<Vec<&mut dyn Observer>> =
+        meters.iter_mut().map(|m| vec![m as &mut dyn Observer]).collect();
+    drive_batch(batch, steps, sample_every, &mut observers);
+    meters.into_iter().zip(batch.worlds()).map(|(m, w)| m.finish(w)).collect()
+}
+
+/// The running state of [`measure_run`], for callers that step the world
+/// themselves: [`sample`](Self::sample) each frame to be measured, then
+/// [`finish`](Self::finish) into the summary. It is an [`Observer`], so it can
+/// also be [`drive`]n alongside other observers.
+pub struct RunMeter {
+    steps: usize,
+    threshold: f32,
+    initial_mass: f64,
+    tracker: Tracker,
+    blobs: BlobTracker,
+    min_mass: f32,
+    shapes: ShapeMoments,
+    complexity: ComplexityMeter,
+    species_cfg: SpeciesConfig,
+    species_tracker: SpeciesTracker,
+    evolution: ActivityMeter,
+    samples: Vec<Sample>,
+    prev_field: Option<Vec<f32>>,
+    /// Step and cumulative `(died, recycled)` at the previous sample.
+    prev_detritus: Option<(usize, (f64, f64))>,
+    sum_conc: f64,
+    sum_comp: f64,
+    sum_act: f64,
+    sum_speed: f64,
+    peak_speed: f32,
+    sum_wavelength: f64,
+    n_wavelength: f64,
+    sum_peak_share: f64,
+    sum_corr_length: f64,
+    sum_energy: f64,
+    sum_live_fraction: f64,
+    sum_turnover: f64,
+    sum_recycling: f64,
+    sum_genome_diversity: f64,
+    sum_lz: f64,
+    sum_statistical: f64,
+    sum_richness: f64,
+    sum_shannon: f64,
+    sum_simpson: f64,
+    n_samples: f64,
+}
+
+impl RunMeter {
+    /// Start measuring a `steps`-step run of `world` from its current state.
+    pub fn new(world: &World, steps: usize, threshold: f32, max_match_dist: f32) -> Self {
+        let species_cfg = SpeciesConfig::default();
+        let track_cfg = TrackConfig { max_match_dist, ..Default::default() };
+        RunMeter {
+            steps,
+            threshold,
+            initial_mass: world.total_mass(),
+            tracker: Tracker::new(world.width(), world.height(), max_match_dist),
+            blobs: BlobTracker::new(world.width(), world.height(), track_cfg),
+            min_mass: track_cfg.min_mass,
+            shapes: ShapeMoments::default(),
+            complexity: ComplexityMeter::new(
+                world.width(),
+                world.height(),
+                CompressionConfig::default(),
+            ),
+            species_tracker: SpeciesTracker::new(species_cfg),
+            species_cfg,
+            evolution: ActivityMeter::new(ActivityConfig::default()),
+            samples: Vec::new(),
+            prev_field: None,
+            prev_detritus: None,
+            sum_conc: 0.0,
+            sum_comp: 0.0,
+            sum_act: 0.0,
+            sum_speed: 0.0,
+            peak_speed: 0.0,
+            sum_wavelength: 0.0,
+            n_wavelength: 0.0,
+            sum_peak_share: 0.0,
+            sum_corr_length: 0.0,
+            sum_energy: 0.0,
+            sum_live_fraction: 0.0,
+            sum_turnover: 0.0,
+            sum_recycling: 0.0,
+            sum_genome_diversity: 0.0,
+            sum_lz: 0.0,
+            sum_statistical: 0.0,
+            sum_richness: 0.0,
+            sum_shannon: 0.0,
+            sum_simpson: 0.0,
+            n_samples: 0.0,
         }
+    }
+
+    /// Measure `world` as it stands at `step`.
+    pub fn sample(&mut self, world: &World, step: usize) {
+        let threshold = self.threshold;
         let field = world.mass_field();
         let stats = field_stats(&field, threshold);
-        let comps = connected_components(&field, world.width(), world.height(), threshold);
-        let vel = tracker.observe(&comps);
-        let act = match &prev_field {
+        let (w, h) = (world.width(), world.height());
+        let scale = spatial_scale(&field, w, h);
+        let complexity = self.complexity.observe(step, &field);
+        let (comps, labels) = label_components(&field, w, h, threshold);
+        let vel = self.tracker.observe(&comps);
+        for (blob, shape) in comps.blobs.iter().zip(blob_shapes(&field, w, h, &comps, &labels)) {
+            if blob.mass >= self.min_mass {
+                self.shapes.observe(&shape);
+            }
+        }
+        self.blobs.observe_labeled(step, &comps, labels);
+        let act = match &self.prev_field {
             Some(p) => activity(p, &field),
             None => 0.0,
         };
-        prev_field = Some(field);
+        let (census, genome_diversity) = match (world.mu_field(), world.sigma_field()) {
+            (Some(mu), Some(sigma)) => {
+                let mut census =
+                    species_census(&field, mu, sigma, w, h, threshold, &self.species_cfg);
+                self.species_tracker.observe(&mut census);
+                let bins = genome_components(&field, mu, sigma, threshold, &self.species_cfg);
+                self.evolution.observe(step, &bins);
+                (census, Some(bin_entropy(&bins)))
+            }
+            _ => (Census::default(), None),
+        };
+        let energy = world.total_energy();
+        let detritus = world.total_detritus();
+        // Mean per-step turnover since the previous sample, so steps between
+        // samples count; the first sample has only the last step to go on.
+        let totals = world.detritus_totals();
+        let detritus_flux = match (totals, self.prev_detritus) {
+            (Some((died, recycled)), Some((prev_step, (prev_died, prev_recycled)))) => {
+                let elapsed = step.saturating_sub(prev_step).max(1) as f64;
+                Some(((died - prev_died) / elapsed, (recycled - prev_recycled) / elapsed))
+            }
+            _ => world.detritus_flux(),
+        };
+        self.prev_detritus = totals.map(|t| (step, t));
+        self.prev_field = Some(field);
 
-        sum_conc += stats.concentration as f64;
-        sum_comp += comps.count() as f64;
-        sum_act += act as f64;
-        sum_speed += vel.mean_speed as f64;
-        peak_speed = peak_speed.max(vel.max_speed);
-        n_samples += 1.0;
+        self.sum_richness += census.richness() as f64;
+        self.sum_shannon += census.shannon() as f64;
+        self.sum_simpson += census.simpson() as f64;
+        self.sum_conc += stats.concentration as f64;
+        self.sum_comp += comps.count() as f64;
+        self.sum_act += act as f64;
+        self.sum_speed += vel.mean_speed as f64;
+        self.peak_speed = self.peak_speed.max(vel.max_speed);
+        if let Some(wavelength) = scale.dominant_wavelength {
+            self.sum_wavelength += wavelength as f64;
+            self.n_wavelength += 1.0;
+        }
+        self.sum_peak_share += scale.peak_share as f64;
+        self.sum_corr_length += scale.correlation_length as f64;
+        self.sum_energy += energy.unwrap_or(0.0);
+        if let (Some(dead), Some((died, recycled))) = (detritus, detritus_flux) {
+            let matter = (stats.total + dead).max(f64::MIN_POSITIVE);
+            self.sum_live_fraction += stats.total / matter;
+            self.sum_turnover += died / matter;
+            self.sum_recycling += recycled / matter;
+        }
+        self.sum_genome_diversity += genome_diversity.unwrap_or(0.0) as f64;
+        self.sum_lz += complexity.spacetime as f64;
+        self.sum_statistical += complexity.statistical as f64;
+        self.n_samples += 1.0;
 
-        last = Sample {
+        self.samples.push(Sample {
             step,
             stats,
             components: comps.count(),
             largest_mass_fraction: comps.largest_mass_fraction(),
             activity: act,
             velocity: vel,
+            species: census.species,
+            channel_mass: (0..world.params().channels).map(|c| world.channel_mass(c)).collect(),
+            scale,
+            energy,
+            detritus,
+            detritus_flux,
+            genome_diversity,
+            complexity,
+        });
+    }
+
+    /// Fold the samples into the run's summary; `world` is the final state.
+    pub fn finish(self, world: &World) -> (RunSummary, Vec<Sample>) {
+        let last = self.samples.last().cloned().unwrap_or_default();
+        let denom = self.n_samples.max(1.0);
+        let evolution = self.evolution.finish();
+        let narrative = self.blobs.narrative();
+        let summary = RunSummary {
+            steps: self.steps,
+            mass_drift: if self.initial_mass != 0.0 {
+                (world.total_mass() - self.initial_mass).abs() / self.initial_mass
+            } else {
+                0.0
+            },
+            mean_concentration: (self.sum_conc / denom) as f32,
+            mean_components: (self.sum_comp / denom) as f32,
+            final_components: last.components,
+            mean_activity: (self.sum_act / denom) as f32,
+            final_activity: last.activity,
+            mean_speed: (self.sum_speed / denom) as f32,
+            peak_speed: self.peak_speed,
+            mean_richness: (self.sum_richness / denom) as f32,
+            final_richness: last.species.len(),
+            mean_shannon: (self.sum_shannon / denom) as f32,
+            mean_simpson: (self.sum_simpson / denom) as f32,
+            extinctions: self.species_tracker.extinctions,
+            originations: self.species_tracker.originations,
+            new_activity: evolution.series.last().map_or(0.0, |p| p.cumulative_new),
+            evolutionary_class: evolution.class,
+            births: narrative.births,
+            deaths: narrative.deaths,
+            splits: narrative.splits,
+            merges: narrative.merges,
+            event_diversity: narrative.event_diversity(),
+            mean_elongation: self.shapes.mean(0),
+            var_elongation: self.shapes.variance(0),
+            mean_compactness: self.shapes.mean(1),
+            var_compactness: self.shapes.variance(1),
+            mean_holes: self.shapes.mean(2),
+            var_holes: self.shapes.variance(2),
+            mean_symmetry: self.shapes.mean(3),
+            var_symmetry: self.shapes.variance(3),
+            mean_wavelength: (self.sum_wavelength / self.n_wavelength.max(1.0)) as f32,
+            mean_peak_share: (self.sum_peak_share / denom) as f32,
+            mean_correlation_length: (self.sum_corr_length / denom) as f32,
+            mean_energy: self.sum_energy / denom,
+            final_energy: last.energy.unwrap_or(0.0),
+            mean_live_fraction: (self.sum_live_fraction / denom) as f32,
+            mean_turnover: (self.sum_turnover / denom) as f32,
+            mean_recycling: (self.sum_recycling / denom) as f32,
+            mean_genome_diversity: (self.sum_genome_diversity / denom) as f32,
+            mean_lz_complexity: (self.sum_lz / denom) as f32,
+            mean_statistical_complexity: (self.sum_statistical / denom) as f32,
         };
-        samples.push(last.clone());
-    }
-
-    let denom = n_samples.max(1.0);
-    let summary = RunSummary {
-        steps,
-        mass_drift: if initial_mass != 0.0 {
-            (world.total_mass() - initial_mass).abs() / initial_mass
-        } else {
-            0.0
-        },
-        mean_concentration: (sum_conc / denom) as f32,
-        mean_components: (sum_comp / denom) as f32,
-        final_components: last.components,
-        mean_activity: (sum_act / denom) as f32,
-        final_activity: last.activity,
-        mean_speed: (sum_speed / denom) as f32,
-        peak_speed,
-    };
-    (summary, samples)
+        (summary, self.samples)
+    }
+}
+
+impl Observer for RunMeter {
+    fn sample(&mut self, world: &World, step: usize) {
+        RunMeter::sample(self, world, step);
+    }
+}
+
+/// Shannon entropy, in nats, of the mass shares of `bins`.
+fn bin_entropy(bins: &[(u64, f32)]) -> f32 {
+    let total: f64 = bins.iter().map(|b| b.1 as f64).sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let mut h = 0.0f64;
+    for &(_, m) in bins {
+        let p = m as f64 / total;
+        if p > 0.0 {
+            h -= p * p.ln();
+        }
+    }
+    h as f32
+}
+
+/// Running mean and variance of the scalar [`Shape`] descriptors: elongation,
+/// compactness, holes and symmetry order, in that order.
+#[derive(Default)]
+struct ShapeMoments {
+    n: f64,
+    sum: [f64; 4],
+    sum_sq: [f64; 4],
+}
+
+impl ShapeMoments {
+    fn observe(&mut self, shape: &Shape) {
+        let v = [shape.elongation, shape.compactness, shape.holes as f32, shape.symmetry as f32];
+        self.n += 1.0;
+        for (i, &x) in v.iter().enumerate() {
+            self.sum[i] += x as f64;
+            self.sum_sq[i] += x as f64 * x as f64;
+        }
+    }
+
+    fn mean(&self, i: usize) -> f32 {
+        (self.sum[i] / self.n.max(1.0)) as f32
+    }
+
+    fn variance(&self, i: usize) -> f32 {
+        let mean = self.sum[i] / self.n.max(1.0);
+        (self.sum_sq[i] / self.n.max(1.0) - mean * mean).max(0.0) as f32
+    }
+}
+
+/// Mass-weighted mean flow speed `|F|` of the last step, over every channel,
+/// or `None` if the world is not recording diagnostics (or held no matter).
+pub fn mean_flow_magnitude(world: &World) -> Option<f32> {
+    let (mut sum, mut mass) = (0.0f64, 0.0f64);
+    for c in 0..world.params().channels {
+        let (fx, fy) = world.flow_field(c)?;
+        let m = world.transported_mass(c)?;
+        for ((&x, &y), &mi) in fx.iter().zip(fy).zip(m) {
+            sum += (x * x + y * y).sqrt() as f64 * mi as f64;
+            mass += mi as f64;
+        }
+    }
+    (mass > 0.0).then(|| (sum / mass) as f32)
+}
+
+/// Fraction of the last step's transported mass whose displacement hit the
+/// `max_flow` clamp — near 0 in a well-resolved run; large values mean the
+/// clamp, not the physics, is shaping transport. `None` without diagnostics.
+pub fn clamped_fraction(world: &World) -> Option<f32> {
+    let (mut clamped, mut mass) = (0.0f64, 0.0f64);
+    for c in 0..world.params().channels {
+        let mask = world.clamp_mask(c)?;
+        let m = world.transported_mass(c)?;
+        for (&k, &mi) in mask.iter().zip(m) {
+            mass += mi as f64;
+            if k {
+                clamped += mi as f64;
+            }
+        }
+    }
+    (mass > 0.0).then(|| (clamped / mass) as f32)
+}
+
+/// Mass-weighted mean of the energy gate `g(E)` over the last step: 1 when
+/// energy never limited affinity, toward 0 when starvation cancelled it.
+/// `None` without diagnostics.
+pub fn mean_energy_gate(world: &World) -> Option<f32> {
+    let gate = world.gate_field()?;
+    let (mut sum, mut mass) = (0.0f64, 0.0f64);
+    for c in 0..world.params().channels {
+        let m = world.transported_mass(c)?;
+        for (&g, &mi) in gate.iter().zip(m) {
+            sum += g as f64 * mi as f64;
+            mass += mi as f64;
+        }
+    }
+    (mass > 0.0).then(|| (sum / mass) as f32)
+}
+
+/// Stochastic replicates of one initial condition: for each seed, clone
+/// `world`, reseed its noise stream, and [`measure_run`] the copy. `world` is
+/// left untouched. Without noise enabled every replicate is identical — the
+/// spread of the returned summaries is the run's sensitivity to noise.
+pub fn measure_replicates(
+    world: &World,
+    seeds: &[u64],
+    steps: usize,
+    sample_every: usize,
+    threshold: f32,
+    max_match_dist: f32,
+) -> Vec<RunSummary> {
+    seeds
+        .iter()
+        .map(|&seed| {
+            let mut replica = world.clone();
+            replica.set_noise_seed(seed);
+            measure_run(&mut replica, steps, sample_every, threshold, max_match_dist).0
+        })
+        .collect()
 }
 
 /// One sampled snapshot of a run.
@@ -490,6 +2065,35 @@ pub struct Sample {
     pub largest_mass_fraction: f32,
     pub activity: f32,
     pub velocity: VelocityStats,
+    /// Species census with tracked IDs (empty unless the genome is enabled).
+    pub species: Vec<Species>,
+    /// Mass held by each matter channel — the trophic readout (F5).
+    pub channel_mass: Vec<f64>,
+    /// Spatial scale of the total matter field.
+    pub scale: SpatialScale,
+    /// Energy stored across the world (`None` without the energy economy).
+    pub energy: Option<f64>,
+    /// Dead mass held as detritus (`None` without the detritus cycle).
+    pub detritus: Option<f64>,
+    /// Matter that died and matter that was recycled per step, as `(died,
+    /// recycled)` averaged over the steps since the previous sample (the last
+    /// step alone for the first sample; `None` without the detritus cycle).
+    pub detritus_flux: Option<(f64, f64)>,
+    /// Shannon entropy, in nats, of occupied mass over the genome bins of
+    /// [`genome_components`] (`None` without a genome).
+    pub genome_diversity: Option<f32>,
+    /// Compression complexity of the matter field.
+    pub complexity: CompressionComplexity,
+}
+
+impl Sample {
+    /// Live share of all matter, live plus detritus (`None` without the
+    /// detritus cycle).
+    pub fn live_fraction(&self) -> Option<f32> {
+        let dead = self.detritus?;
+        let matter = self.stats.total + dead;
+        Some(if matter > 0.0 { (self.stats.total / matter) as f32 } else { 0.0 })
+    }
 }
 
 impl Default for FieldStats {
@@ -607,6 +2211,187 @@ mod tests {
         assert!((v.mean_speed - 5.0).abs() < 1e-4, "3-4-5 triangle, got {}", v.mean_speed);
     }
 
+    #[test]
+    fn census_separates_distinct_genomes() {
+        let (w, h) = (32, 32);
+        let mut mass = vec![0.0f32; w * h];
+        let mut mu = vec![0.15f32; w * h];
+        let sigma = vec![0.017f32; w * h];
+        // Species A: a 4×4 block at μ=0.13. Species B: two 3×3 blocks at μ=0.17.
+        for (x0, y0, size, m) in [(4usize, 4usize, 4usize, 0.13f32), (20, 4, 3, 0.17), (20, 20, 3, 0.17)] {
+            for y in y0..y0 + size {
+                for x in x0..x0 + size {
+                    mass[y * w + x] = 0.8;
+                    mu[y * w + x] = m;
+                }
+            }
+        }
+        let census = species_census(&mass, &mu, &sigma, w, h, 0.05, &SpeciesConfig::default());
+        assert_eq!(census.richness(), 2);
+        // B is heavier (18 cells vs 16), so it leads the census.
+        let (b, a) = (&census.species[0], &census.species[1]);
+        assert!((a.mu - 0.13).abs() < 1e-6 && a.cells == 16 && a.blobs == 1);
+        assert!((b.mu - 0.17).abs() < 1e-6 && b.cells == 18 && b.blobs == 2);
+        assert!(b.extent > a.extent, "two distant blobs spread wider than one block");
+        assert_eq!(census.noise_mass, 0.0);
+        // Near-equal abundances: Simpson just under its two-species max of 0.5.
+        assert!(census.simpson() > 0.45 && census.simpson() <= 0.5);
+        assert!((census.shannon() - 2f32.ln()).abs() < 0.01);
+    }
+
+    #[test]
+    fn species_tracker_counts_extinction_and_origination() {
+        let sp = |mu: f32, mass: f32| Species {
+            id: 0,
+            mu,
+            sigma: 0.017,
+            mass,
+            cells: 1,
+            blobs: 1,
+            cx: 0.0,
+            cy: 0.0,
+            extent: 0.0,
+        };
+        let mut tracker = SpeciesTracker::new(SpeciesConfig::default());
+        let mut c0 = Census { species: vec![sp(0.13, 5.0), sp(0.17, 4.0)], noise_mass: 0.0 };
+        tracker.observe(&mut c0);
+        let (a, b) = (c0.species[0].id, c0.species[1].id);
+        assert_ne!(a, b);
+        // Species B drifts slightly but keeps its identity; A is gone; C is new.
+        let mut c1 = Census { species: vec![sp(0.171, 6.0), sp(0.25, 2.0)], noise_mass: 0.0 };
+        tracker.observe(&mut c1);
+        assert_eq!(c1.species[0].id, b);
+        assert!(c1.species[1].id != a && c1.species[1].id != b);
+        assert_eq!(tracker.extinctions, 1);
+        assert_eq!(tracker.originations, 1);
+    }
+
+    #[test]
+    fn measure_run_censuses_species_with_genome() {
+        let mut world = World::new(96, 96, FlowLeniaParams::default());
+        world.enable_genome();
+        world.seed_species(28.0, 32.0, 7.0, 0.95, 0.13, 0.017);
+        world.seed_species(66.0, 60.0, 7.0, 0.95, 0.17, 0.017);
+        let (summary, samples) = measure_run(&mut world, 60, 20, 0.05, 8.0);
+        // Two painted genomes, plus the default-genome halo of the Gaussian tails.
+        assert!(samples[0].species.len() >= 2);
+        assert!(summary.mean_richness >= 1.5, "richness {}", summary.mean_richness);
+        assert!(summary.mean_shannon > 0.0);
+        assert!(summary.evolutionary_class.is_some());
+        assert!(samples.iter().all(|s| s.genome_diversity.is_some_and(|d| d > 0.0)));
+        assert!(summary.mean_genome_diversity > 0.0);
+        let series = abundance_series(&samples);
+        assert!(series.len() >= 2);
+        assert!(series.iter().all(|(_, s)| s.len() == samples.len()));
+    }
+
+    /// Feed an activity meter `samples` frames of `frame(t)`.
+    fn activity_of(
+        samples: usize,
+        frame: impl Fn(usize) -> Vec<(u64, f32)>,
+    ) -> EvolutionaryActivity {
+        let mut meter = ActivityMeter::new(ActivityConfig::default());
+        for t in 0..samples {
+            meter.observe(t, &frame(t));
+        }
+        meter.finish()
+    }
+
+    #[test]
+    fn persistent_success_is_adaptive_activity() {
+        // Two components that keep their unequal masses: the shadow deals the
+        // masses out at random, so only the real heavy one runs far above a*.
+        let run = activity_of(200, |_| vec![(1, 10.0), (2, 1.0)]);
+        assert!(run.excess > 3.0, "excess {}", run.excess);
+        assert_eq!(run.class, Some(EvolutionaryClass::Bounded));
+        let last = run.series.last().unwrap();
+        assert_eq!(last.diversity, 2);
+        assert!((last.total - 2200.0).abs() < 1e-6 && (last.mean - 1100.0).abs() < 1e-6);
+        assert!((last.shadow_total - last.total).abs() < 1e-6, "the shadow conserves mass");
+        assert!(run.series.iter().any(|p| p.new > 0.0) && last.cumulative_new > 0.0);
+
+        // Keep adding components, every other one successful: adaptive
+        // activity that does not level off.
+        let open_ended = activity_of(400, |t| {
+            (0..=t as u64 / 4).map(|k| (k, if k % 2 == 0 { 10.0 } else { 1.0 })).collect()
+        });
+        assert_eq!(open_ended.class, Some(EvolutionaryClass::Unbounded));
+    }
+
+    #[test]
+    fn neutral_dynamics_show_no_new_activity() {
+        // Equal masses leave nothing for selection to favor: real and shadow
+        // activity coincide, however many components there are.
+        let steady = activity_of(200, |_| (0..5).map(|k| (k, 1.0)).collect());
+        assert!(steady.excess < 2.0, "excess {}", steady.excess);
+        assert_eq!(steady.class, Some(EvolutionaryClass::None));
+        let growing = activity_of(200, |t| (0..=t as u64 / 4).map(|k| (k, 1.0)).collect());
+        assert_eq!(growing.class, Some(EvolutionaryClass::UnboundedDiversity));
+        assert_eq!(activity_of(0, |_| Vec::new()).class, None);
+    }
+
+    #[test]
+    fn genome_components_bin_occupied_mass() {
+        let cfg = SpeciesConfig::default();
+        let mass = [0.5, 0.5, 0.01, 0.3];
+        let mu = [0.1201, 0.1202, 0.3, 0.2];
+        let sigma = [0.0151, 0.0151, 0.02, 0.0151];
+        let bins = genome_components(&mass, &mu, &sigma, 0.05, &cfg);
+        assert_eq!(bins.len(), 2);
+        assert!((bins.iter().map(|b| b.1).sum::<f32>() - 1.3).abs() < 1e-6);
+        assert!(bins.iter().any(|b| (b.1 - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn measure_run_reads_out_channel_masses() {
+        use crate::flow_lenia::TrophicLink;
+        let params = FlowLeniaParams { channels: 2, ..FlowLeniaParams::default() };
+        let mut world = World::new(64, 64, params);
+        world.seed_blob(0, 32.0, 32.0, 7.0, 0.8);
+        world.seed_blob(1, 36.0, 32.0, 5.0, 0.8);
+        let link = TrophicLink { predator: 1, prey: 0, rate: 0.5, energy_yield: 0.0 };
+        world.enable_trophic(vec![link]);
+        let (summary, samples) = measure_run(&mut world, 40, 10, 0.05, 8.0);
+        assert!(summary.mass_drift < 1e-4, "drift {}", summary.mass_drift);
+        assert_eq!(summary.evolutionary_class, None, "no genome, no evolutionary activity");
+        let series = channel_mass_series(&samples);
+        assert_eq!(series.len(), 2);
+        assert!(series.iter().all(|s| s.len() == samples.len()));
+        let (prey, pred) = (&series[0], &series[1]);
+        assert!(prey.last().unwrap() < prey.first().unwrap());
+        assert!(pred.last().unwrap() > pred.first().unwrap());
+    }
+
+    #[test]
+    fn diagnostics_helpers_need_diagnostics() {
+        let mut world = World::new(48, 48, FlowLeniaParams::default());
+        world.seed_blob(0, 24.0, 24.0, 6.0, 0.95);
+        world.step();
+        assert!(mean_flow_magnitude(&world).is_none());
+        assert!(clamped_fraction(&world).is_none());
+        world.enable_diagnostics();
+        world.step();
+        let speed = mean_flow_magnitude(&world).unwrap();
+        assert!(speed > 0.0 && speed.is_finite());
+        // The default regime is well resolved: the clamp should not bite.
+        assert!(clamped_fraction(&world).unwrap() < 0.01);
+        assert!((mean_energy_gate(&world).unwrap() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn replicates_differ_only_by_noise_seed() {
+        use crate::flow_lenia::NoiseParams;
+        let mut world = World::new(48, 48, FlowLeniaParams::default());
+        world.seed_blob(0, 24.0, 24.0, 6.0, 0.95);
+        world.enable_noise(NoiseParams { seed: 0, flow: 0.5, exchange: 0.5 });
+        let runs = measure_replicates(&world, &[1, 1, 2], 30, 10, 0.05, 8.0);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0], runs[1]);
+        assert_ne!(runs[0], runs[2]);
+        assert!(runs.iter().all(|r| r.mass_drift < 1e-4));
+        assert_eq!(world.time(), 0, "the template world is not stepped");
+    }
+
     #[test]
     fn measure_run_reports_conservation_and_structure() {
         let mut world = World::new(64, 64, FlowLeniaParams::default());
@@ -617,5 +2402,207 @@ mod tests {
         // A seeded blob should register as at least one concentrated component.
         assert!(summary.mean_components >= 1.0);
         assert!(summary.mean_concentration > 0.0);
+        // No genome, no species.
+        assert_eq!(summary.mean_richness, 0.0);
+        assert!(samples.iter().all(|s| s.species.is_empty()));
+        // No economy, no economy readout.
+        assert!(samples.iter().all(|s| s.energy.is_none() && s.live_fraction().is_none()));
+        assert_eq!((summary.mean_energy, summary.mean_turnover), (0.0, 0.0));
+        // A lone blob is structured: compressible, but not trivially so.
+        assert!(summary.mean_lz_complexity > 0.0 && summary.mean_lz_complexity < 0.5);
+        assert!(summary.mean_statistical_complexity > 0.0);
+        assert!(samples.iter().all(|s| s.complexity.spatial > 0.0));
+    }
+
+    #[test]
+    fn measure_run_reads_out_the_economy() {
+        use crate::flow_lenia::{DetritusParams, EnergyParams};
+        // Starved: no source, so matter dies into detritus and recycles.
+        let params = FlowLeniaParams { kernel_radius: 7, ..FlowLeniaParams::default() };
+        let mut world = World::new(48, 48, params);
+        world.enable_energy(EnergyParams::default());
+        world.enable_detritus(DetritusParams::default());
+        world.seed_blob(0, 24.0, 24.0, 6.0, 0.95);
+        let (summary, samples) = measure_run(&mut world, 100, 10, 0.05, 8.0);
+        assert!(samples.iter().all(|s| s.energy.is_some() && s.detritus_flux.is_some()));
+        assert_eq!(samples[0].live_fraction(), Some(1.0));
+        // Fluxes are per-step means over the whole interval between samples,
+        // so they account for the detritus pool's change across it.
+        for pair in samples.windows(2) {
+            let (died, recycled) = pair[1].detritus_flux.unwrap();
+            let steps = (pair[1].step - pair[0].step) as f64;
+            let change = pair[1].detritus.unwrap() - pair[0].detritus.unwrap();
+            let tol = 1e-4 * world.total_mass().max(1.0);
+            assert!((change - (died - recycled) * steps).abs() < tol, "at {}", pair[1].step);
+        }
+        let last = samples.last().unwrap();
+        let live = last.live_fraction().unwrap();
+        assert!(live > 0.0 && live < 0.9, "live fraction {live}");
+        assert!(summary.mean_live_fraction > live && summary.mean_live_fraction < 1.0);
+        assert!(summary.mean_turnover > 0.0 && summary.mean_recycling > 0.0);
+        assert!(summary.final_energy > 0.0, "recycling releases energy");
+        assert_eq!(summary.final_energy, last.energy.unwrap());
+        assert_eq!(summary.mean_genome_diversity, 0.0);
+    }
+
+    #[test]
+    fn spatial_scale_tells_stripes_from_spots_and_soup() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        let tau = std::f32::consts::TAU;
+        // Stripes of period 8, on a power-of-two world and on one that is not.
+        for (w, h) in [(64, 64), (48, 40)] {
+            let stripes: Vec<f32> =
+                (0..w * h).map(|i| 0.5 + 0.5 * (tau * (i % w) as f32 / 8.0).sin()).collect();
+            let s = spatial_scale(&stripes, w, h);
+            assert_eq!(s.dominant_wavelength, Some(8.0), "{w}×{h}");
+            assert!(s.peak_share > 0.95);
+            assert!((s.correlation[0] - 1.0).abs() < 1e-4);
+            assert!(s.correlation_length > 1.0 && s.correlation_length < 3.0);
+        }
+
+        let (w, h) = (64, 64);
+        let spot: Vec<f32> = (0..w * h)
+            .map(|i| {
+                let (dx, dy) = ((i % w) as f32 - 32.0, (i / w) as f32 - 32.0);
+                (-(dx * dx + dy * dy) / 18.0).exp()
+            })
+            .collect();
+        let s = spatial_scale(&spot, w, h);
+        assert_eq!(s.dominant_wavelength, Some(64.0));
+        assert!(s.peak_share < 0.5);
+        assert!(s.correlation_length > 2.0, "{}", s.correlation_length);
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let soup: Vec<f32> = (0..w * h).map(|_| rng.gen::<f32>()).collect();
+        let s = spatial_scale(&soup, w, h);
+        assert!(s.correlation_length < 1.0);
+        assert!(s.peak_share < 0.1);
+
+        let s = spatial_scale(&vec![0.3; w * h], w, h);
+        assert_eq!((s.dominant_wavelength, s.correlation_length), (None, 0.0));
+    }
+
+    #[test]
+    fn dft_matches_the_direct_sum() {
+        // Radix-2, Bluestein, and the degenerate single sample.
+        for n in [16, 12, 48, 1] {
+            let x: Vec<f64> = (0..n).map(|i| ((i * 7) % 5) as f64 - 1.5).collect();
+            let (mut re, mut im) = (x.clone(), vec![0.0; n]);
+            let dft = Dft::new(n);
+            dft.apply(&mut re, &mut im, false);
+            for k in 0..n {
+                let (mut sr, mut si) = (0.0, 0.0);
+                for (t, &v) in x.iter().enumerate() {
+                    let a = -std::f64::consts::TAU * (k * t) as f64 / n as f64;
+                    sr += v * a.cos();
+                    si += v * a.sin();
+                }
+                assert!((re[k] - sr).abs() < 1e-9 && (im[k] - si).abs() < 1e-9, "n {n} k {k}");
+            }
+            // The inverse undoes it, up to the factor n.
+            dft.apply(&mut re, &mut im, true);
+            for (r, v) in re.iter().zip(&x) {
+                assert!((r / n as f64 - v).abs() < 1e-9, "n {n}");
+            }
+        }
+    }
+
+    /// Fill the cells within `r` of `(cx, cy)` on a `w × w` torus, leaving an
+    /// inner radius `hole` empty.
+    fn disk(field: &mut [f32], w: usize, (cx, cy): (f32, f32), r: f32, hole: f32) {
+        for (i, v) in field.iter_mut().enumerate() {
+            let dx = wrap_delta((i % w) as f32 - cx, w as f32);
+            let dy = wrap_delta((i / w) as f32 - cy, w as f32);
+            let d2 = dx * dx + dy * dy;
+            if d2 <= r * r && d2 >= hole * hole {
+                *v = 1.0;
+            }
+        }
+    }
+
+    fn shapes_of(field: &[f32], w: usize) -> Vec<Shape> {
+        let (comps, labels) = label_components(field, w, w, 0.5);
+        blob_shapes(field, w, w, &comps, &labels)
+    }
+
+    #[test]
+    fn shapes_tell_disk_ring_dumbbell_and_crescent_apart() {
+        let w = 40;
+        let mut f = vec![0.0; w * w];
+        disk(&mut f, w, (20.0, 20.0), 8.0, 0.0);
+        let disk_shape = &shapes_of(&f, w)[0];
+        assert!(disk_shape.elongation < 1.05, "{disk_shape:?}");
+        assert_eq!((disk_shape.holes, disk_shape.euler(), disk_shape.symmetry), (0, 1, 0));
+        assert!(disk_shape.compactness > 0.5, "{disk_shape:?}");
+
+        let mut f = vec![0.0; w * w];
+        disk(&mut f, w, (20.0, 20.0), 8.0, 5.0);
+        let ring = &shapes_of(&f, w)[0];
+        assert_eq!((ring.holes, ring.euler(), ring.symmetry), (1, 0, 0));
+        assert!(ring.compactness < disk_shape.compactness / 2.0);
+        let peak = (0..ring.radial_profile.len())
+            .max_by(|&a, &b| ring.radial_profile[a].total_cmp(&ring.radial_profile[b]))
+            .unwrap();
+        assert!((5..=8).contains(&peak), "{:?}", ring.radial_profile);
+        assert_eq!(ring.radial_profile[0], 0.0);
+
+        // Two lobes joined by a bar, lying along y = x, across the seam.
+        let mut f = vec![0.0; w * w];
+        disk(&mut f, w, (34.0, 34.0), 4.0, 0.0);
+        disk(&mut f, w, (46.0, 46.0), 4.0, 0.0);
+        for t in 34..=46 {
+            f[(t % w) * w + t % w] = 1.0;
+            f[(t % w) * w + (t + 1) % w] = 1.0;
+        }
+        let dumbbell = &shapes_of(&f, w)[0];
+        assert_eq!(dumbbell.symmetry, 2, "{dumbbell:?}");
+        assert!(dumbbell.elongation > 2.0);
+        assert!((dumbbell.orientation - std::f32::consts::FRAC_PI_4).abs() < 0.05);
+
+        let mut f = vec![0.0; w * w];
+        disk(&mut f, w, (20.0, 20.0), 8.0, 0.0);
+        let mut bite = vec![0.0; w * w];
+        disk(&mut bite, w, (24.0, 20.0), 7.0, 0.0);
+        for (v, b) in f.iter_mut().zip(&bite) {
+            *v -= b;
+        }
+        let crescent = &shapes_of(&f, w)[0];
+        assert_eq!((crescent.symmetry, crescent.holes), (1, 0), "{crescent:?}");
+        assert!(crescent.orientation.abs() > 1.4, "{crescent:?}");
+
+        let mut f = vec![0.0; w * w];
+        disk(&mut f, w, (20.0, 20.0), 3.0, 0.0);
+        for k in 0..3 {
+            let a = k as f32 * std::f32::consts::TAU / 3.0;
+            disk(&mut f, w, (20.0 + 6.0 * a.cos(), 20.0 + 6.0 * a.sin()), 3.5, 0.0);
+        }
+        let trefoil = &shapes_of(&f, w)[0];
+        assert_eq!(trefoil.symmetry, 3, "{trefoil:?}");
+        assert!(trefoil.elongation < 1.1);
+    }
+
+    #[test]
+    fn measure_run_counts_narrative_events() {
+        // A small blob under the short-range rule divides.
+        let params = FlowLeniaParams { kernel_radius: 7, ..FlowLeniaParams::default() };
+        let mut world = World::new(48, 48, params.clone());
+        world.seed_blob(0, 24.0, 24.0, 2.5, 0.9);
+        let (summary, samples) = measure_run(&mut world, 300, 5, 0.05, 8.0);
+        assert!(samples.last().unwrap().components >= 2);
+        assert!(summary.splits >= 1, "{summary:?}");
+        assert!(summary.event_diversity >= 1);
+        // A blob left alone tells no story.
+        let mut world = World::new(48, 48, FlowLeniaParams { growth_mu: 0.45, ..params });
+        world.seed_blob(0, 24.0, 24.0, 3.0, 0.9);
+        let (summary, _) = measure_run(&mut world, 100, 5, 0.05, 8.0);
+        assert_eq!((summary.births, summary.deaths, summary.splits, summary.merges), (0, 0, 0, 0));
+        assert_eq!(summary.event_diversity, 0);
+        // One round blob: its shape descriptors hold still.
+        assert!(summary.mean_elongation >= 1.0 && summary.mean_elongation < 1.2);
+        assert!(summary.var_elongation < 1e-2 && summary.var_holes == 0.0);
+        assert!(summary.mean_compactness > 0.5);
+        // Isolated structure: its scale is the world's, not a pattern's.
+        assert_eq!(summary.mean_wavelength, 48.0);
+        assert!(summary.mean_correlation_length > 1.0);
     }
 }
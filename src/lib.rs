@@ -1,4 +1,7 @@
 pub mod analysis;
+pub mod behavior;
+pub mod collision;
+pub mod creature;
 pub mod emergence;
 pub mod flow_lenia;
 pub mod grid;
@@ -7,7 +10,10 @@ pub mod search;
 #[cfg(feature = "gpu")]
 pub mod gpu;
 pub mod lab;
+pub mod library;
 pub mod narrative;
+pub mod observer;
 pub mod render;
 pub mod rules;
 pub mod sim;
+pub mod tracking;
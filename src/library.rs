@@ -0,0 +1,449 @@
+//! Creature library: a persistent, deduplicated catalog of discoveries.
+//!
+//! Searches and examples find good creatures and then forget them. A
+//! [`Library`] is a directory that accumulates them across runs: each entry
+//! keeps the [`Creature`] itself (matter, local genome, rule), the search
+//! genome if it came from one, the harness [`RunSummary`] of its run, and a
+//! rendered GIF thumbnail next to it for browsing.
+//!
+//! Entries are deduplicated by a [`Signature`] that ignores orientation: the
+//! radial mass profile around the centroid, the elongation of the body, its
+//! mass, and the behavior the run measured (speed, activity, blob count). A
+//! new find within [`Library::tolerance`] of a stored one is the same creature
+//! — it replaces the stored one only if it scored higher. [`Query`] filters
+//! the catalog by behavior, e.g. motile single blobs faster than `0.3`.
+//!
+//! Layout: `<dir>/<id>.ron` holds the entry, `<dir>/<id>.gif` its thumbnail.
+
+use crate::creature::Creature;
+use crate::harness::RunSummary;
+use crate::search::{eval_world, EvalConfig, Evaluated, Genome};
+use std::borrow::Cow;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Rings of the radial mass profile.
+pub const PROFILE_BINS: usize = 16;
+/// Width of each profile ring, in cells.
+const RING_WIDTH: f32 = 2.0;
+/// Thumbnail pixels per creature cell.
+const THUMB_CELL: usize = 4;
+
+/// A rotation-invariant fingerprint of a creature's shape and behavior.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Signature {
+    /// Fraction of mass in each [`RING_WIDTH`]-cell ring around the centroid;
+    /// the last ring collects everything further out.
+    pub profile: [f32; PROFILE_BINS],
+    /// `1 − λ_min/λ_max` of the mass inertia tensor: 0 round, → 1 line-like.
+    pub elongation: f32,
+    /// Total mass.
+    pub mass: f32,
+    /// Mean blob speed over the run.
+    pub speed: f32,
+    /// Mean activity over the run.
+    pub activity: f32,
+    /// Mean blob count over the run.
+    pub components: f32,
+}
+
+impl Signature {
+    /// Fingerprint `creature` as its run measured it.
+    pub fn new(creature: &Creature, summary: &RunSummary) -> Self {
+        let (w, h) = (creature.width, creature.height);
+        let (cx, cy) = creature.center;
+        let mut mass = vec![0.0f64; w * h];
+        for c in 0..creature.params.channels {
+            for (m, &v) in mass.iter_mut().zip(creature.channel(c)) {
+                *m += v.max(0.0) as f64;
+            }
+        }
+        let total: f64 = mass.iter().sum();
+        let mut profile = [0.0f32; PROFILE_BINS];
+        let (mut ixx, mut iyy, mut ixy) = (0.0f64, 0.0f64, 0.0f64);
+        if total > 0.0 {
+            for (p, &m) in mass.iter().enumerate() {
+                let (dx, dy) = ((p % w) as f32 - cx, (p / w) as f32 - cy);
+                let ring = ((dx * dx + dy * dy).sqrt() / RING_WIDTH) as usize;
+                profile[ring.min(PROFILE_BINS - 1)] += (m / total) as f32;
+                ixx += m * (dx * dx) as f64;
+                iyy += m * (dy * dy) as f64;
+                ixy += m * (dx * dy) as f64;
+            }
+        }
+        // Eigenvalues of the 2×2 inertia tensor.
+        let (mean, diff) = ((ixx + iyy) * 0.5, ((ixx - iyy) * 0.5).hypot(ixy));
+        let elongation =
+            if mean + diff > 0.0 { (2.0 * diff / (mean + diff)) as f32 } else { 0.0 };
+        Signature {
+            profile,
+            elongation,
+            mass: total as f32,
+            speed: summary.mean_speed,
+            activity: summary.mean_activity,
+            components: summary.mean_components,
+        }
+    }
+
+    /// Dissimilarity: the L1 distance between mass profiles (up to 2), plus
+    /// the elongation difference, plus relative differences of mass, speed,
+    /// activity and blob count. Near 0 for the same creature seen at any rotation.
+    pub fn distance(&self, other: &Signature) -> f32 {
+        let rel = |a: f32, b: f32, floor: f32| (a - b).abs() / a.abs().max(b.abs()).max(floor);
+        let profile: f32 =
+            self.profile.iter().zip(&other.profile).map(|(a, b)| (a - b).abs()).sum();
+        profile
+            + (self.elongation - other.elongation).abs()
+            + rel(self.mass, other.mass, 1.0)
+            + rel(self.speed, other.speed, 0.1)
+            + rel(self.activity, other.activity, 0.01)
+            + rel(self.components, other.components, 1.0)
+    }
+}
+
+/// One catalogued creature.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// Library-assigned identifier, also the entry's file stem. Empty until
+    /// the entry is added.
+    pub id: String,
+    /// The search genome the creature was found with, if any.
+    pub genome: Option<Genome>,
+    pub creature: Creature,
+    /// Harness summary of the run it was found in.
+    pub summary: RunSummary,
+    /// Quality the finding search gave it; ranks duplicates.
+    pub quality: f32,
+    pub signature: Signature,
+}
+
+impl Entry {
+    /// An entry for `creature`, measured as `summary`.
+    pub fn new(
+        creature: Creature,
+        summary: RunSummary,
+        genome: Option<Genome>,
+        quality: f32,
+    ) -> Self {
+        let signature = Signature::new(&creature, &summary);
+        Entry { id: String::new(), genome, creature, summary, quality, signature }
+    }
+
+    /// Turn a search elite into an entry: replay its evaluation run and cut
+    /// out the largest blob left at the end. `None` if the run left no blob.
+    pub fn from_elite(elite: &Evaluated, cfg: &EvalConfig) -> Option<Self> {
+        let mut world = eval_world(&elite.genome, cfg);
+        for _ in 0..cfg.steps {
+            world.step();
+        }
+        let creature = Creature::extract(&world, 0, cfg.threshold)?;
+        let genome = Some(elite.genome.clone());
+        Some(Entry::new(creature, elite.summary.clone(), genome, elite.quality))
+    }
+}
+
+/// What [`Library::add`] did with an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Added {
+    /// Stored under a new id.
+    New(String),
+    /// A better-scoring duplicate of this entry, stored in its place.
+    Replaced(String),
+    /// A duplicate of this entry, no better; not stored.
+    Duplicate(String),
+}
+
+/// Filter over library entries; every bound that is set must hold.
+#[derive(Clone, Debug, Default)]
+pub struct Query {
+    pub min_speed: Option<f32>,
+    pub max_speed: Option<f32>,
+    pub min_activity: Option<f32>,
+    pub max_activity: Option<f32>,
+    /// Upper bound on the mean blob count.
+    pub max_components: Option<f32>,
+    pub min_mass: Option<f32>,
+    pub max_mass: Option<f32>,
+    pub min_quality: Option<f32>,
+}
+
+impl Query {
+    /// Motile creatures: mean speed above `speed`.
+    pub fn speed_above(mut self, speed: f32) -> Self {
+        self.min_speed = Some(speed);
+        self
+    }
+
+    /// Mean activity above `activity`.
+    pub fn activity_above(mut self, activity: f32) -> Self {
+        self.min_activity = Some(activity);
+        self
+    }
+
+    /// Creatures that stayed one blob (mean blob count at most 1.5).
+    pub fn single_blob(mut self) -> Self {
+        self.max_components = Some(1.5);
+        self
+    }
+
+    /// Quality above `quality`.
+    pub fn quality_above(mut self, quality: f32) -> Self {
+        self.min_quality = Some(quality);
+        self
+    }
+
+    /// Whether `entry` passes every bound.
+    pub fn matches(&self, entry: &Entry) -> bool {
+        let s = &entry.summary;
+        let above = |v: f32, bound: Option<f32>| bound.is_none_or(|b| v > b);
+        let below = |v: f32, bound: Option<f32>| bound.is_none_or(|b| v <= b);
+        above(s.mean_speed, self.min_speed)
+            && below(s.mean_speed, self.max_speed)
+            && above(s.mean_activity, self.min_activity)
+            && below(s.mean_activity, self.max_activity)
+            && below(s.mean_components, self.max_components)
+            && above(entry.signature.mass, self.min_mass)
+            && below(entry.signature.mass, self.max_mass)
+            && above(entry.quality, self.min_quality)
+    }
+}
+
+/// A `.ron` file [`Library::open`] could not load: unreadable, or not an entry.
+#[derive(Debug)]
+pub struct Skipped {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+/// A directory of catalogued creatures.
+pub struct Library {
+    dir: PathBuf,
+    entries: Vec<Entry>,
+    /// Signature distance below which two creatures are the same.
+    pub tolerance: f32,
+}
+
+impl Library {
+    /// Open the library at `dir`, creating the directory if needed and loading
+    /// every entry in it. A file that cannot be read or parsed does not fail
+    /// the open: it is left alone on disk and returned among the [`Skipped`].
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<(Library, Vec<Skipped>)> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        let (mut entries, mut skipped) = (Vec::new(), Vec::new());
+        for item in std::fs::read_dir(&dir)? {
+            let path = item?.path();
+            if path.extension().is_some_and(|e| e == "ron") {
+                let entry = std::fs::read_to_string(&path).and_then(|text| {
+                    ron::de::from_str::<Entry>(&text)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                });
+                match entry {
+                    Ok(entry) => entries.push(entry),
+                    Err(error) => skipped.push(Skipped { path, error }),
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        skipped.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok((Library { dir, entries, tolerance: 0.25 }, skipped))
+    }
+
+    /// Every entry, in id order.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The entry with id `id`.
+    pub fn get(&self, id: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    /// The stored entry closest to `signature`, if any lies within tolerance.
+    pub fn find_duplicate(&self, signature: &Signature) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .map(|e| (e, e.signature.distance(signature)))
+            .filter(|&(_, d)| d < self.tolerance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(e, _)| e)
+    }
+
+    /// Catalogue `entry` unless it duplicates a stored creature; a duplicate
+    /// with higher quality replaces the stored one under the same id.
+    pub fn add(&mut self, mut entry: Entry) -> io::Result<Added> {
+        if let Some(dup) = self.find_duplicate(&entry.signature) {
+            let id = dup.id.clone();
+            if entry.quality <= dup.quality {
+                return Ok(Added::Duplicate(id));
+            }
+            entry.id = id.clone();
+            self.write(&entry)?;
+            let slot =
+                self.entries.iter().position(|e| e.id == id).expect("duplicate is stored");
+            self.entries[slot] = entry;
+            return Ok(Added::Replaced(id));
+        }
+        let mut next =
+            self.entries.iter().filter_map(|e| e.id.parse::<u32>().ok()).max().map_or(1, |n| n + 1);
+        // Never overwrite a file `open` skipped.
+        while self.dir.join(format!("{next:04}.ron")).exists() {
+            next += 1;
+        }
+        entry.id = format!("{next:04}");
+        self.write(&entry)?;
+        let id = entry.id.clone();
+        self.entries.push(entry);
+        Ok(Added::New(id))
+    }
+
+    /// Entries passing `query`, in id order.
+    pub fn query(&self, query: &Query) -> Vec<&Entry> {
+        self.entries.iter().filter(|e| query.matches(e)).collect()
+    }
+
+    /// Write an entry and its thumbnail.
+    fn write(&self, entry: &Entry) -> io::Result<()> {
+        let text = ron::ser::to_string_pretty(entry, ron::ser::PrettyConfig::default())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(self.dir.join(format!("{}.ron", entry.id)), text)?;
+        write_thumbnail(&self.dir.join(format!("{}.gif", entry.id)), &entry.creature)
+    }
+}
+
+/// Render a creature's total mass as a grayscale GIF, normalized to its peak.
+fn write_thumbnail(path: &Path, creature: &Creature) -> io::Result<()> {
+    let (w, h) = (creature.width, creature.height);
+    let mut mass = vec![0.0f32; w * h];
+    for c in 0..creature.params.channels {
+        for (m, &v) in mass.iter_mut().zip(creature.channel(c)) {
+            *m += v;
+        }
+    }
+    let peak = mass.iter().cloned().fold(0.0f32, f32::max).max(1e-6);
+    let (gw, gh) = (w * THUMB_CELL, h * THUMB_CELL);
+    let mut pixels = vec![0u8; gw * gh];
+    for (p, px) in pixels.iter_mut().enumerate() {
+        let (x, y) = ((p % gw) / THUMB_CELL, (p / gw) / THUMB_CELL);
+        *px = ((mass[y * w + x] / peak).clamp(0.0, 1.0) * 255.0) as u8;
+    }
+    let palette: Vec<u8> = (0..=255u8).flat_map(|v| [v, v, v]).collect();
+    let to_io = |e: gif::EncodingError| io::Error::other(e);
+    let mut encoder =
+        gif::Encoder::new(File::create(path)?, gw as u16, gh as u16, &palette).map_err(to_io)?;
+    let frame = gif::Frame {
+        width: gw as u16,
+        height: gh as u16,
+        buffer: Cow::Owned(pixels),
+        ..Default::default()
+    };
+    encoder.write_frame(&frame).map_err(to_io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_lenia::{FlowLeniaParams, World};
+
+    /// A creature cut from a fresh blob of the given radius, stamped at `angle`.
+    fn creature(radius: f32, angle: f32) -> Creature {
+        let mut world = World::new(64, 64, FlowLeniaParams::default());
+        world.seed_blob(0, 32.0, 32.0, radius, 0.9);
+        // Squash it so rotation matters.
+        for (i, v) in world.channel_mut(0).iter_mut().enumerate() {
+            *v *= if (i / 64).abs_diff(32) < 3 { 1.0 } else { 0.3 };
+        }
+        let flat = Creature::extract(&world, 0, 0.05).unwrap();
+        let mut turned = World::new(64, 64, FlowLeniaParams::default());
+        flat.stamp(&mut turned, 32.0, 32.0, angle);
+        Creature::extract(&turned, 0, 0.05).unwrap()
+    }
+
+    fn summary(speed: f32, components: f32) -> RunSummary {
+        RunSummary { mean_speed: speed, mean_components: components, ..RunSummary::default() }
+    }
+
+    fn temp_library(tag: &str) -> (PathBuf, Library) {
+        let dir = std::env::temp_dir().join(format!("library-{tag}-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let (library, skipped) = Library::open(&dir).unwrap();
+        assert!(skipped.is_empty());
+        (dir, library)
+    }
+
+    #[test]
+    fn signature_ignores_rotation_but_not_shape() {
+        let s = summary(0.5, 1.0);
+        let base = Signature::new(&creature(6.0, 0.0), &s);
+        let turned = Signature::new(&creature(6.0, 1.1), &s);
+        let bigger = Signature::new(&creature(9.0, 0.0), &s);
+        assert!(base.elongation > 0.2, "the test creature should be elongated");
+        assert!(base.distance(&turned) < 0.1, "rotation moved it {}", base.distance(&turned));
+        assert!(base.distance(&bigger) > 0.5, "size barely registered");
+        let slower = Signature::new(&creature(6.0, 0.0), &summary(0.1, 1.0));
+        assert!(base.distance(&slower) > 0.5, "behavior barely registered");
+    }
+
+    #[test]
+    fn library_deduplicates_and_persists() {
+        let (dir, mut library) = temp_library("dedup");
+        let first = Entry::new(creature(6.0, 0.0), summary(0.5, 1.0), None, 0.4);
+        assert_eq!(library.add(first).unwrap(), Added::New("0001".into()));
+        assert!(dir.join("0001.gif").exists() && dir.join("0001.ron").exists());
+        // The same creature turned: a duplicate; better scored, it replaces.
+        let turned = Entry::new(creature(6.0, 2.0), summary(0.5, 1.0), None, 0.3);
+        assert_eq!(library.add(turned.clone()).unwrap(), Added::Duplicate("0001".into()));
+        let better = Entry { quality: 0.9, ..turned };
+        assert_eq!(library.add(better).unwrap(), Added::Replaced("0001".into()));
+        let other = Entry::new(creature(9.0, 0.0), summary(0.05, 3.0), None, 0.2);
+        assert_eq!(library.add(other).unwrap(), Added::New("0002".into()));
+
+        let (reopened, _) = Library::open(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(reopened.entries(), library.entries());
+        assert_eq!(reopened.get("0001").unwrap().quality, 0.9);
+    }
+
+    #[test]
+    fn open_skips_bad_entries() {
+        let (dir, mut library) = temp_library("skip");
+        let entry = Entry::new(creature(6.0, 0.0), summary(0.5, 1.0), None, 0.4);
+        library.add(entry).unwrap();
+        std::fs::write(dir.join("0002.ron"), "not an entry").unwrap();
+        let (mut reopened, skipped) = Library::open(&dir).unwrap();
+        assert_eq!(reopened.len(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, dir.join("0002.ron"));
+        assert_eq!(skipped[0].error.kind(), io::ErrorKind::InvalidData);
+        // The bad file keeps its id: a new find does not overwrite it.
+        let other = Entry::new(creature(9.0, 0.0), summary(0.05, 3.0), None, 0.2);
+        assert_eq!(reopened.add(other).unwrap(), Added::New("0003".into()));
+        let bad = std::fs::read_to_string(dir.join("0002.ron")).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(bad, "not an entry");
+    }
+
+    #[test]
+    fn query_filters_by_behavior() {
+        let (dir, mut library) = temp_library("query");
+        for (radius, speed, comps) in [(5.0, 0.6, 1.0), (7.0, 0.05, 1.0), (9.0, 0.8, 4.0)] {
+            let entry = Entry::new(creature(radius, 0.0), summary(speed, comps), None, 0.5);
+            library.add(entry).unwrap();
+        }
+        std::fs::remove_dir_all(&dir).ok();
+        let motile = library.query(&Query::default().speed_above(0.3).single_blob());
+        assert_eq!(motile.len(), 1);
+        assert_eq!(motile[0].summary.mean_speed, 0.6);
+        assert_eq!(library.query(&Query::default().speed_above(0.3)).len(), 2);
+        assert_eq!(library.query(&Query::default()).len(), 3);
+    }
+}
@@ -0,0 +1,321 @@
+//! Streaming observers: metrics as pluggable listeners on a run.
+//!
+//! [`measure_run`](crate::harness::measure_run) folds a fixed set of metrics
+//! into a [`RunSummary`](crate::harness::RunSummary). To compute anything else
+//! — or to print a live time series — implement [`Observer`] and hand it to
+//! [`drive`], which owns the stepping loop: it samples step 0, every
+//! `sample_every` steps and the last step, feeding every observer in turn,
+//! then lets each one [`finish`](Observer::finish). Observers compose by
+//! passing several; each reads what it needs from the world.
+//!
+//! Built-ins record one harness metric per sample as a `(step, value)` series:
+//! [`FieldStatsObserver`], [`ComponentsObserver`], [`TrackerObserver`]
+//! (velocity), [`ActivityObserver`] and [`EnergyObserver`]; a
+//! [`ComplexityMeter`] observes the matter field's compression complexity.
+//! The harness's own
+//! [`RunMeter`](crate::harness::RunMeter) is an observer too, so a custom
+//! metric can ride along with the standard summary in one run. A closure
+//! `FnMut(&World, usize)` is an observer as well.
+
+use crate::emergence::ComplexityMeter;
+use crate::flow_lenia::{World, WorldBatch};
+use crate::harness::{
+    activity, connected_components, field_stats, mean_energy_gate, Components, FieldStats,
+    Tracker, VelocityStats,
+};
+
+/// A listener on a run: sampled at chosen steps, told when the run ends.
+pub trait Observer {
+    /// Look at `world` as it stands at `step` (0 is the initial state).
+    fn sample(&mut self, world: &World, step: usize);
+
+    /// The run is over; `world` is its final state.
+    fn finish(&mut self, _world: &World) {}
+}
+
+impl<F: FnMut(&World, usize)> Observer for F {
+    fn sample(&mut self, world: &World, step: usize) {
+        self(world, step)
+    }
+}
+
+/// Whether [`drive`] samples `step` of a `steps`-step run.
+fn is_sampled(step: usize, steps: usize, sample_every: usize) -> bool {
+    step.is_multiple_of(sample_every.max(1)) || step == steps
+}
+
+/// Step `world` forward `steps` steps, sampling every observer at step 0,
+/// every `sample_every` steps and the last step, then finish them all.
+pub fn drive(
+    world: &mut World,
+    steps: usize,
+    sample_every: usize,
+    observers: &mut [&mut dyn Observer],
+) {
+    for step in 0..=steps {
+        if step > 0 {
+            world.step();
+        }
+        if is_sampled(step, steps, sample_every) {
+            for o in observers.iter_mut() {
+                o.sample(world, step);
+            }
+        }
+    }
+    for o in observers.iter_mut() {
+        o.finish(world);
+    }
+}
+
+/// [`drive`] over every world of a [`WorldBatch`], stepped in lockstep:
+/// `observers[i]` watches world `i`.
+pub fn drive_batch(
+    batch: &mut WorldBatch,
+    steps: usize,
+    sample_every: usize,
+    observers: &mut [Vec<&mut dyn Observer>],
+) {
+    debug_assert_eq!(observers.len(), batch.worlds().len());
+    for step in 0..=steps {
+        if step > 0 {
+            batch.step();
+        }
+        if is_sampled(step, steps, sample_every) {
+            for (list, world) in observers.iter_mut().zip(batch.worlds()) {
+                for o in list.iter_mut() {
+                    o.sample(world, step);
+                }
+            }
+        }
+    }
+    for (list, world) in observers.iter_mut().zip(batch.worlds()) {
+        for o in list.iter_mut() {
+            o.finish(world);
+        }
+    }
+}
+
+/// [`FieldStats`] of the matter field at each sample.
+pub struct FieldStatsObserver {
+    pub threshold: f32,
+    pub series: Vec<(usize, FieldStats)>,
+}
+
+impl FieldStatsObserver {
+    pub fn new(threshold: f32) -> Self {
+        FieldStatsObserver { threshold, series: Vec::new() }
+    }
+}
+
+impl Observer for FieldStatsObserver {
+    fn sample(&mut self, world: &World, step: usize) {
+        self.series.push((step, field_stats(&world.mass_field(), self.threshold)));
+    }
+}
+
+/// The matter field's blobs at each sample.
+pub struct ComponentsObserver {
+    pub threshold: f32,
+    pub series: Vec<(usize, Components)>,
+}
+
+impl ComponentsObserver {
+    pub fn new(threshold: f32) -> Self {
+        ComponentsObserver { threshold, series: Vec::new() }
+    }
+}
+
+impl Observer for ComponentsObserver {
+    fn sample(&mut self, world: &World, step: usize) {
+        let comps = connected_components(
+            &world.mass_field(),
+            world.width(),
+            world.height(),
+            self.threshold,
+        );
+        self.series.push((step, comps));
+    }
+}
+
+/// Blob velocities at each sample, from a [`Tracker`] matching the matter
+/// field's blobs across samples.
+pub struct TrackerObserver {
+    pub threshold: f32,
+    pub tracker: Tracker,
+    pub series: Vec<(usize, VelocityStats)>,
+}
+
+impl TrackerObserver {
+    pub fn new(w: usize, h: usize, threshold: f32, max_match_dist: f32) -> Self {
+        TrackerObserver {
+            threshold,
+            tracker: Tracker::new(w, h, max_match_dist),
+            series: Vec::new(),
+        }
+    }
+}
+
+impl Observer for TrackerObserver {
+    fn sample(&mut self, world: &World, step: usize) {
+        let comps = connected_components(
+            &world.mass_field(),
+            world.width(),
+            world.height(),
+            self.threshold,
+        );
+        self.series.push((step, self.tracker.observe(&comps)));
+    }
+}
+
+/// [`activity`] of the matter field between consecutive samples (0 at the
+/// first).
+#[derive(Default)]
+pub struct ActivityObserver {
+    prev: Option<Vec<f32>>,
+    pub series: Vec<(usize, f32)>,
+}
+
+impl ActivityObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for ActivityObserver {
+    fn sample(&mut self, world: &World, step: usize) {
+        let field = world.mass_field();
+        let act = self.prev.as_ref().map_or(0.0, |p| activity(p, &field));
+        self.prev = Some(field);
+        self.series.push((step, act));
+    }
+}
+
+/// One sample of the energy economy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnergyReading {
+    /// Energy stored across the world.
+    pub total: f64,
+    /// [`FieldStats`] of the energy field.
+    pub stats: FieldStats,
+    /// Mass-weighted mean energy gate of the last step, if the world records
+    /// diagnostics (see [`mean_energy_gate`]).
+    pub mean_gate: Option<f32>,
+}
+
+/// The energy field at each sample; records nothing for a world without the
+/// energy economy.
+pub struct EnergyObserver {
+    pub threshold: f32,
+    pub series: Vec<(usize, EnergyReading)>,
+}
+
+impl EnergyObserver {
+    pub fn new(threshold: f32) -> Self {
+        EnergyObserver { threshold, series: Vec::new() }
+    }
+}
+
+impl Observer for EnergyObserver {
+    fn sample(&mut self, world: &World, step: usize) {
+        let (Some(field), Some(total)) = (world.energy_field(), world.total_energy()) else {
+            return;
+        };
+        let reading = EnergyReading {
+            total,
+            stats: field_stats(field, self.threshold),
+            mean_gate: mean_energy_gate(world),
+        };
+        self.series.push((step, reading));
+    }
+}
+
+impl Observer for ComplexityMeter {
+    fn sample(&mut self, world: &World, step: usize) {
+        self.observe(step, &world.mass_field());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_lenia::{EnergyParams, FlowLeniaParams};
+    use crate::harness::{measure_run, RunMeter};
+
+    fn world() -> World {
+        let params = FlowLeniaParams { kernel_radius: 7, ..FlowLeniaParams::default() };
+        let mut world = World::new(32, 32, params);
+        world.seed_blob(0, 16.0, 16.0, 4.0, 0.9);
+        world
+    }
+
+    #[test]
+    fn drive_feeds_every_observer_the_same_samples() {
+        let mut world = world();
+        let mut stats = FieldStatsObserver::new(0.05);
+        let mut comps = ComponentsObserver::new(0.05);
+        let mut tracker = TrackerObserver::new(32, 32, 0.05, 8.0);
+        let mut act = ActivityObserver::new();
+        let mut energy = EnergyObserver::new(0.05);
+        let (mut steps, mut finished) = (Vec::new(), 0);
+        let mut custom = |_: &World, step: usize| steps.push(step);
+        struct Finish<'a>(&'a mut usize);
+        impl Observer for Finish<'_> {
+            fn sample(&mut self, _: &World, _: usize) {}
+            fn finish(&mut self, _: &World) {
+                *self.0 += 1;
+            }
+        }
+        drive(
+            &mut world,
+            25,
+            10,
+            &mut [
+                &mut stats,
+                &mut comps,
+                &mut tracker,
+                &mut act,
+                &mut energy,
+                &mut custom,
+                &mut Finish(&mut finished),
+            ],
+        );
+        assert_eq!(steps, vec![0, 10, 20, 25]);
+        assert_eq!(finished, 1);
+        fn at<T>(series: &[(usize, T)]) -> Vec<usize> {
+            series.iter().map(|p| p.0).collect()
+        }
+        assert_eq!(at(&stats.series), steps);
+        assert_eq!(at(&comps.series), steps);
+        assert_eq!(at(&tracker.series), steps);
+        assert_eq!(at(&act.series), steps);
+        assert_eq!(act.series[0].1, 0.0);
+        assert!(act.series[1].1 > 0.0);
+        assert!(comps.series.iter().all(|(_, c)| c.count() >= 1));
+        // No economy, no readings.
+        assert!(energy.series.is_empty());
+    }
+
+    #[test]
+    fn energy_observer_reads_the_economy() {
+        let mut world = world();
+        world.enable_energy(EnergyParams::default());
+        let mut energy = EnergyObserver::new(0.0);
+        drive(&mut world, 4, 2, &mut [&mut energy]);
+        assert_eq!(energy.series.len(), 3);
+        assert!(energy.series.iter().all(|(_, r)| r.total >= 0.0 && r.mean_gate.is_none()));
+    }
+
+    #[test]
+    fn run_meter_rides_along_with_other_observers() {
+        let mut world = world();
+        let mut meter = RunMeter::new(&world, 30, 0.05, 8.0);
+        let mut stats = FieldStatsObserver::new(0.05);
+        drive(&mut world, 30, 10, &mut [&mut meter, &mut stats]);
+        let (summary, samples) = meter.finish(&world);
+        let (expected, expected_samples) = measure_run(&mut self::world(), 30, 10, 0.05, 8.0);
+        assert_eq!(summary, expected);
+        assert_eq!(samples, expected_samples);
+        assert_eq!(stats.series.len(), samples.len());
+        assert!(stats.series.iter().zip(&samples).all(|((_, s), sample)| *s == sample.stats));
+    }
+}
@@ -9,7 +9,9 @@
 //!
 //! - **Genome** — 7 continuous Flow-Lenia parameters (growth μ/σ, kernel ring
 //!   peak/width, dt, critical mass θ_A, ramp exponent n). Kernel radius is fixed
-//!   so evaluation cost is constant and comparable.
+//!   so evaluation cost is constant and comparable. Optional kernel-shape genes
+//!   (angular harmonic, twist, ring offset/eccentricity) are pinned to a radial
+//!   kernel by the default bounds; [`Bounds::anisotropic`] opens them up.
 //! - **Behavior descriptors** (the map axes) come straight from the harness
 //!   `RunSummary`: mean concentration (how localized matter becomes) × mean
 //!   activity (dynamism — frozen vs. churning). These separate dead/uniform from
@@ -21,10 +23,17 @@
 //!   M-γ-2's job (the energy economy). The deliverable here is the filled map.
 //!
 //! Every genome is evaluated from the *same* fixed random soup, so differences
-//! reflect the rule, not the seed.
+//! reflect the rule, not the seed. Runs are checked step by step for broken
+//! invariants (NaN, negative matter, mass drift); a genome that breaks one is
+//! marked invalid and never ranked. Each evaluation thread steps its genomes'
+//! worlds in lockstep as a `WorldBatch`, which gives the same results as
+//! evaluating them one by one, several times faster.
 
-use crate::flow_lenia::{DetritusParams, EnergyParams, FlowLeniaParams, KernelRing, World};
-use crate::harness::{measure_run, RunSummary, Sample};
+use crate::flow_lenia::{
+    DetritusParams, EnergyParams, FlowLeniaParams, InvariantViolation, KernelRing, KernelShape,
+    World, WorldBatch,
+};
+use crate::harness::{measure_batch, measure_run, RunSummary, Sample};
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 
@@ -59,8 +68,19 @@ const D_DEATH: usize = 0; // starved-matter death rate
 const D_RECYCLE_MATTER: usize = 1; // detritus → live return rate
 const D_RECYCLE_ENERGY: usize = 2; // energy released per unit matter decomposed
 
+/// Number of searchable **kernel-shape** genes ([`KernelShape`]). Read by every
+/// objective, but the default bounds pin them to a radial kernel, so they only
+/// vary when the search is given [`Bounds::anisotropic`].
+pub const N_SHAPE_GENES: usize = 5;
+// Shape-gene indices.
+const S_HARMONIC: usize = 0; // harmonic order k (rounded to an integer)
+const S_AMPLITUDE: usize = 1; // harmonic amplitude
+const S_TWIST: usize = 2; // harmonic phase twist (chirality)
+const S_OFFSET: usize = 3; // ring displacement along the orientation
+const S_ECCENTRICITY: usize = 4; // ring eccentricity
+
 /// A point in Flow-Lenia rule space, plus its energy-economy genes.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Genome {
     /// The 7 rule genes (growth, kernel ring, dt, θ_A, ramp n).
     pub genes: [f32; N_GENES],
@@ -68,6 +88,9 @@ pub struct Genome {
     pub energy: [f32; N_ENERGY_GENES],
     /// The 3 detritus-cycle genes. Only read under [`Objective::Ecosystem`].
     pub detritus: [f32; N_DETRITUS_GENES],
+    /// The 5 kernel-shape genes (harmonic order, amplitude, twist, offset,
+    /// eccentricity). All zero = radial kernel.
+    pub shape: [f32; N_SHAPE_GENES],
 }
 
 /// What the search rewards, and therefore which behavior it illuminates.
@@ -118,6 +141,8 @@ pub struct Bounds {
     pub energy_hi: [f32; N_ENERGY_GENES],
     pub detritus_lo: [f32; N_DETRITUS_GENES],
     pub detritus_hi: [f32; N_DETRITUS_GENES],
+    pub shape_lo: [f32; N_SHAPE_GENES],
+    pub shape_hi: [f32; N_SHAPE_GENES],
 }
 
 impl Default for Bounds {
@@ -138,6 +163,8 @@ impl Default for Bounds {
         //                       death recycle_m recycle_e
         let detritus_lo = [0.01f32, 0.002, 0.00];
         let detritus_hi = [0.15f32, 0.050, 0.60];
+        // Shape genes are pinned to zero (a radial kernel) unless the caller
+        // opts into anisotropy.
         Bounds {
             lo,
             hi,
@@ -145,6 +172,24 @@ impl Default for Bounds {
             energy_hi,
             detritus_lo,
             detritus_hi,
+            shape_lo: [0.0; N_SHAPE_GENES],
+            shape_hi: [0.0; N_SHAPE_GENES],
+        }
+    }
+}
+
+impl Bounds {
+    /// Default bounds with the kernel-shape genes opened up, so the search can
+    /// reach directed (odd harmonic, offset ring) and rotating (twisted
+    /// harmonic) regions of rule space.
+    pub fn anisotropic() -> Self {
+        //                  k     amp   twist offset ecc
+        let shape_lo = [0.0f32, 0.0, -6.0, 0.0, 0.0];
+        let shape_hi = [3.0f32, 1.0, 6.0, 0.4, 0.8];
+        Bounds {
+            shape_lo,
+            shape_hi,
+            ..Bounds::default()
         }
     }
 }
@@ -164,10 +209,19 @@ impl Genome {
         for (i, gene) in detritus.iter_mut().enumerate() {
             *gene = rng.gen_range(b.detritus_lo[i]..=b.detritus_hi[i]);
         }
+        // Pinned shape genes draw nothing, so the default (radial) search
+        // consumes the random stream exactly as before shape genes existed.
+        let mut shape = b.shape_lo;
+        for (i, gene) in shape.iter_mut().enumerate() {
+            if b.shape_lo[i] != b.shape_hi[i] {
+                *gene = rng.gen_range(b.shape_lo[i]..=b.shape_hi[i]);
+            }
+        }
         Genome {
             genes,
             energy,
             detritus,
+            shape,
         }
     }
 
@@ -191,16 +245,29 @@ impl Genome {
             *gene =
                 (*gene + gaussian(rng) * sigma * range).clamp(b.detritus_lo[i], b.detritus_hi[i]);
         }
+        let mut shape = self.shape;
+        for (i, gene) in shape.iter_mut().enumerate() {
+            let range = b.shape_hi[i] - b.shape_lo[i];
+            if range == 0.0 {
+                *gene = b.shape_lo[i];
+                continue;
+            }
+            *gene = (*gene + gaussian(rng) * sigma * range).clamp(b.shape_lo[i], b.shape_hi[i]);
+        }
         Genome {
             genes,
             energy,
             detritus,
+            shape,
         }
     }
 
-    /// Map the rule genes to substrate parameters (single channel, one ring).
+    /// Map the rule and shape genes to substrate parameters (single channel,
+    /// one ring). Orientation is left at 0: in an isotropic world it only
+    /// rotates the behavior.
     pub fn to_params(&self, kernel_radius: usize) -> FlowLeniaParams {
         let g = &self.genes;
+        let s = &self.shape;
         FlowLeniaParams {
             channels: 1,
             kernel_radius,
@@ -209,6 +276,14 @@ impl Genome {
                 width: g[WIDTH],
                 weight: 1.0,
             }],
+            shape: KernelShape {
+                harmonic: s[S_HARMONIC].round() as u32,
+                amplitude: s[S_AMPLITUDE],
+                twist: s[S_TWIST],
+                offset: s[S_OFFSET],
+                eccentricity: s[S_ECCENTRICITY],
+                orientation: 0.0,
+            },
             growth_mu: g[MU],
             growth_sigma: g[SIGMA],
             dt: g[DT],
@@ -229,6 +304,7 @@ impl Genome {
             gate_half: e[E_GATE],
             consume: e[E_CONSUME],
             maintain: e[E_MAINTAIN],
+            chemotaxis: 0.0,
         }
     }
 
@@ -256,14 +332,17 @@ pub struct EvalConfig {
     pub seed: u64,
     /// What to reward / illuminate.
     pub objective: Objective,
+    /// Relative conserved-mass drift past which a run is invalid. Every
+    /// evaluation runs the world's invariant validator with this tolerance.
+    pub mass_tolerance: f64,
 }
 
 impl Default for EvalConfig {
     fn default() -> Self {
-        // Sized for a tractable pure-CPU search. Scalar direct convolution at
-        // radius 13 is the cost floor here — batching many worlds on the GPU is
-        // F2's real throughput play (see docs/mgamma-plan.md); on CPU we keep the
-        // grid and horizon modest and let parallel evaluation carry the load.
+        // Sized for a tractable pure-CPU search. Direct convolution at radius 13
+        // is the cost floor here — batching many worlds on the GPU is F2's real
+        // throughput play (see docs/mgamma-plan.md); on CPU we keep the grid and
+        // horizon modest and let batched, parallel evaluation carry the load.
         EvalConfig {
             grid_size: 48,
             kernel_radius: 13,
@@ -272,6 +351,7 @@ impl Default for EvalConfig {
             threshold: 0.05,
             seed: 20240703,
             objective: Objective::Liveness,
+            mass_tolerance: 1e-3,
         }
     }
 }
@@ -287,10 +367,45 @@ pub struct Evaluated {
     /// Final-frame concentration and occupancy (for interpreting liveness).
     pub final_concentration: f32,
     pub final_occupied: f32,
+    /// The invariant the run broke (NaN, negative mass, mass drift), if any.
+    /// Invalid genomes score zero and are never placed in the archive.
+    pub violation: Option<InvariantViolation>,
+}
+
+impl Evaluated {
+    /// Whether the run kept every invariant.
+    pub fn is_valid(&self) -> bool {
+        self.violation.is_none()
+    }
 }
 
 /// Run one genome from the shared fixed soup and measure it.
 pub fn evaluate(genome: &Genome, cfg: &EvalConfig) -> Evaluated {
+    let mut world = eval_world(genome, cfg);
+    let (summary, samples) =
+        measure_run(&mut world, cfg.steps, cfg.sample_every, cfg.threshold, 8.0);
+    score(genome, cfg, &world, summary, &samples)
+}
+
+/// [`evaluate`] a batch of genomes, stepping their worlds in lockstep as one
+/// [`WorldBatch`] — the same results in input order, for a fraction of the
+/// cost of running them one by one.
+pub fn evaluate_batch(genomes: &[Genome], cfg: &EvalConfig) -> Vec<Evaluated> {
+    let worlds = genomes.iter().map(|g| eval_world(g, cfg)).collect();
+    let mut batch = WorldBatch::new(worlds);
+    let runs = measure_batch(&mut batch, cfg.steps, cfg.sample_every, cfg.threshold, 8.0);
+    genomes
+        .iter()
+        .zip(batch.worlds())
+        .zip(runs)
+        .map(|((g, world), (summary, samples))| score(g, cfg, world, summary, &samples))
+        .collect()
+}
+
+/// The world a genome is evaluated in, ready to run: its rule, the objective's
+/// economy if any, the shared soup and the invariant validator. Stepping it
+/// `cfg.steps` times reproduces the evaluated run exactly.
+pub fn eval_world(genome: &Genome, cfg: &EvalConfig) -> World {
     let params = genome.to_params(cfg.kernel_radius);
     let mut world = World::new(cfg.grid_size, cfg.grid_size, params);
     let c = cfg.grid_size as f32 * 0.5;
@@ -320,9 +435,20 @@ pub fn evaluate(genome: &Genome, cfg: &EvalConfig) -> Evaluated {
     // behavior differences are attributable to the rule (and economy).
     let mut rng = StdRng::seed_from_u64(cfg.seed);
     world.seed_random_patch(&mut rng, 0, c, c, cfg.grid_size as f32 / 3.0, 0.6);
+    // A pathological rule can blow up to NaN or negative matter; catch it here
+    // rather than let it poison the summary and win a cell.
+    world.enable_validation(cfg.mass_tolerance);
+    world
+}
 
-    let (summary, samples) =
-        measure_run(&mut world, cfg.steps, cfg.sample_every, cfg.threshold, 8.0);
+/// Score a finished run of `genome` under the configured objective.
+fn score(
+    genome: &Genome,
+    cfg: &EvalConfig,
+    world: &World,
+    summary: RunSummary,
+    samples: &[Sample],
+) -> Evaluated {
     let (final_concentration, final_occupied) = samples
         .last()
         .map(|s: &Sample| (s.stats.concentration, s.stats.occupied_fraction))
@@ -355,13 +481,15 @@ pub fn evaluate(genome: &Genome, cfg: &EvalConfig) -> Evaluated {
             (summary.mean_concentration, summary.mean_activity),
         ),
     };
+    let violation = world.violation().cloned();
     Evaluated {
         genome: genome.clone(),
         summary,
-        quality,
+        quality: if violation.is_some() { 0.0 } else { quality },
         bd,
         final_concentration,
         final_occupied,
+        violation,
     }
 }
 
@@ -481,7 +609,11 @@ impl MapElites {
 
     /// Insert an evaluated genome, keeping it only if its cell is empty or it
     /// beats the incumbent's quality. Returns true if it was placed.
+    /// Runs that broke an invariant are rejected outright.
     pub fn insert(&mut self, e: Evaluated) -> bool {
+        if !e.is_valid() {
+            return false;
+        }
         let idx = self.cell_index(e.bd);
         match &self.cells[idx] {
             Some(cur) if cur.quality >= e.quality => false,
@@ -562,9 +694,14 @@ impl Default for SearchConfig {
     }
 }
 
+/// Worlds stepped together per thread: enough to amortize each kernel tap's
+/// offset arithmetic, few enough that the batch's matter stays in cache.
+const BATCH_WORLDS: usize = 16;
+
 /// Evaluate a batch of genomes in parallel with scoped threads. Cost per genome
 /// is essentially constant (grid × steps × kernel), so contiguous chunking is
-/// balanced. Results are returned in input order.
+/// balanced. Each thread steps its chunk as [`WorldBatch`]es of up to
+/// [`BATCH_WORLDS`] worlds. Results are returned in input order.
 fn parallel_eval(genomes: &[Genome], cfg: &EvalConfig) -> Vec<Evaluated> {
     let n = genomes.len();
     if n == 0 {
@@ -585,8 +722,9 @@ fn parallel_eval(genomes: &[Genome], cfg: &EvalConfig) -> Vec<Evaluated> {
                 break;
             }
             let slice = &genomes[start..end];
-            handles
-                .push(s.spawn(move || slice.iter().map(|g| evaluate(g, cfg)).collect::<Vec<_>>()));
+            handles.push(s.spawn(move || {
+                slice.chunks(BATCH_WORLDS).flat_map(|b| evaluate_batch(b, cfg)).collect::<Vec<_>>()
+            }));
         }
         for h in handles {
             out.extend(h.join().expect("eval thread panicked"));
@@ -690,6 +828,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn default_bounds_keep_the_kernel_radial() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let g = Genome::random(&mut rng, &Bounds::default());
+        assert!(g.to_params(13).shape.is_radial());
+        let m = g.mutate(&mut rng, &Bounds::default(), 0.5);
+        assert!(m.to_params(13).shape.is_radial());
+    }
+
+    #[test]
+    fn pinned_shape_genes_draw_nothing() {
+        // Default bounds pin every shape gene: the random stream is consumed
+        // exactly as by the rule, energy and detritus genes alone.
+        let b = Bounds::default();
+        let (mut rng, mut replay) = (StdRng::seed_from_u64(19), StdRng::seed_from_u64(19));
+        let g = Genome::random(&mut rng, &b);
+        let m = g.mutate(&mut rng, &b, 0.5);
+        for i in 0..N_GENES {
+            replay.gen_range(b.lo[i]..=b.hi[i]);
+        }
+        for i in 0..N_ENERGY_GENES {
+            replay.gen_range(b.energy_lo[i]..=b.energy_hi[i]);
+        }
+        for i in 0..N_DETRITUS_GENES {
+            replay.gen_range(b.detritus_lo[i]..=b.detritus_hi[i]);
+        }
+        for _ in 0..N_GENES + N_ENERGY_GENES + N_DETRITUS_GENES {
+            gaussian(&mut replay);
+        }
+        assert_eq!(rng.gen::<u64>(), replay.gen::<u64>());
+        assert_eq!((g.shape, m.shape), ([0.0; N_SHAPE_GENES], [0.0; N_SHAPE_GENES]));
+    }
+
+    #[test]
+    fn anisotropic_shape_genes_stay_in_bounds_and_map() {
+        let b = Bounds::anisotropic();
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..200 {
+            let g = Genome::random(&mut rng, &b).mutate(&mut rng, &b, 0.5);
+            for i in 0..N_SHAPE_GENES {
+                assert!(
+                    g.shape[i] >= b.shape_lo[i] && g.shape[i] <= b.shape_hi[i],
+                    "s-gene {i} OOB"
+                );
+            }
+            let p = g.to_params(13);
+            assert!(p.shape.harmonic <= 3);
+            assert_eq!(p.shape.twist, g.shape[S_TWIST]);
+            assert_eq!(p.shape.offset, g.shape[S_OFFSET]);
+        }
+    }
+
     #[test]
     fn evaluate_conserves_mass_and_produces_descriptor() {
         let cfg = EvalConfig {
@@ -733,12 +923,14 @@ mod tests {
                 genes: [0.0; N_GENES],
                 energy: [0.0; N_ENERGY_GENES],
                 detritus: [0.0; N_DETRITUS_GENES],
+                shape: [0.0; N_SHAPE_GENES],
             },
             summary: RunSummary::default(),
             quality: q,
             bd,
             final_concentration: 0.0,
             final_occupied: 0.0,
+            violation: None,
         };
         assert!(m.insert(mk(0.5, (0.3, 0.01))));
         assert!(!m.insert(mk(0.4, (0.3, 0.01))), "lower quality, same cell");
@@ -746,6 +938,41 @@ mod tests {
         assert_eq!(m.filled(), 1);
         assert!(m.insert(mk(0.1, (0.5, 0.015))), "different cell");
         assert_eq!(m.filled(), 2);
+        let broken = InvariantViolation::MassDrift { step: 3, expected: 1.0, actual: 2.0 };
+        let invalid = Evaluated { violation: Some(broken), ..mk(5.0, (0.9, 0.02)) };
+        assert!(!m.insert(invalid), "invalid runs never enter the archive");
+        assert_eq!(m.filled(), 2);
+    }
+
+    #[test]
+    fn evaluate_marks_runs_that_break_invariants() {
+        let mut rng = StdRng::seed_from_u64(41);
+        let g = Genome::random(&mut rng, &Bounds::default());
+        let cfg = EvalConfig { grid_size: 32, steps: 20, ..Default::default() };
+        assert!(evaluate(&g, &cfg).is_valid(), "a sane run keeps its invariants");
+        // No run can meet a negative drift tolerance: every one is flagged.
+        let strict = EvalConfig { mass_tolerance: -1.0, ..cfg };
+        let e = evaluate(&g, &strict);
+        assert!(matches!(e.violation, Some(InvariantViolation::MassDrift { step: 1, .. })));
+        assert_eq!(e.quality, 0.0);
+    }
+
+    #[test]
+    fn evaluate_batch_matches_evaluate() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let genomes: Vec<Genome> =
+            (0..5).map(|_| Genome::random(&mut rng, &Bounds::anisotropic())).collect();
+        for objective in [Objective::Liveness, Objective::Ecosystem] {
+            let cfg = EvalConfig { grid_size: 32, steps: 30, objective, ..Default::default() };
+            let batched = evaluate_batch(&genomes, &cfg);
+            assert_eq!(batched.len(), genomes.len());
+            for (g, b) in genomes.iter().zip(&batched) {
+                let alone = evaluate(g, &cfg);
+                assert_eq!(alone.summary, b.summary);
+                assert_eq!((alone.quality, alone.bd), (b.quality, b.bd));
+                assert_eq!(alone.is_valid(), b.is_valid());
+            }
+        }
     }
 
     #[test]
@@ -918,11 +1145,13 @@ mod tests {
             genes: rule,
             energy: [0.35, 0.02, 0.001, 0.15],
             detritus: d,
+            shape: [0.0; N_SHAPE_GENES],
         };
         let punishing = Genome {
             genes: rule,
             energy: [2.0, 0.5, 0.02, 0.0],
             detritus: d,
+            shape: [0.0; N_SHAPE_GENES],
         };
         let cfg = EvalConfig {
             grid_size: 48,
@@ -980,11 +1209,13 @@ mod tests {
             genes: rule,
             energy: econ,
             detritus: [0.05, 0.04, 0.5],
+            shape: [0.0; N_SHAPE_GENES],
         };
         let poor = Genome {
             genes: rule,
             energy: econ,
             detritus: [0.15, 0.002, 0.0],
+            shape: [0.0; N_SHAPE_GENES],
         };
         let cfg = EvalConfig {
             grid_size: 48,
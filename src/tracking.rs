@@ -0,0 +1,563 @@
+//! Persistent blob identities: trajectories, lifetimes and family trees.
+//!
+//! [`harness::Tracker`](crate::harness::Tracker) matches blobs frame to frame
+//! only to read off speeds; it keeps no identities, and a split or merge is
+//! just a mismatch. [`BlobTracker`] keeps them. Each observed frame's blobs
+//! are assigned to the previous frame's by an optimal assignment (Hungarian
+//! method) maximizing a similarity that combines cell overlap, mass ratio and
+//! centroid distance, so two blobs passing close by do not swap identities the
+//! way greedy nearest-centroid matching lets them.
+//!
+//! A matched blob keeps its ID and extends its [`Track`]. Overlap links what
+//! the assignment leaves over: a new blob overlapping a blob of the previous
+//! frame split off it, a vanished blob overlapping a current one merged into
+//! it. Those become [`BlobEvent`]s with parent IDs, so a track records where
+//! it came from and where it went — lifetimes, displacement histories and
+//! family trees of organisms, exportable as a per-blob [`table`](BlobTracker::table).
+
+use crate::harness::{label_components, Blob, Components};
+use crate::narrative::NarrativeStats;
+use std::fmt::Write as _;
+
+/// Weights and gate of the frame-to-frame similarity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackConfig {
+    /// Largest centroid distance, in cells, at which two blobs that do not
+    /// overlap may still be matched.
+    pub max_match_dist: f32,
+    /// Weight of cell overlap (intersection over union).
+    pub overlap_weight: f32,
+    /// Weight of mass similarity (lighter over heavier).
+    pub mass_weight: f32,
+    /// Weight of centroid proximity (`1 − distance / max_match_dist`).
+    pub distance_weight: f32,
+    /// Blobs lighter than this are ignored as noise, so a flicker of matter
+    /// crossing the threshold is neither born nor dies.
+    pub min_mass: f32,
+}
+
+impl Default for TrackConfig {
+    fn default() -> Self {
+        TrackConfig {
+            max_match_dist: 8.0,
+            overlap_weight: 1.0,
+            mass_weight: 0.5,
+            distance_weight: 0.5,
+            min_mass: 1.0,
+        }
+    }
+}
+
+/// One observation of a tracked blob.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackPoint {
+    pub step: usize,
+    /// Centroid, unwrapped: continuous across the torus seam, so differences
+    /// of points are true displacements.
+    pub x: f32,
+    pub y: f32,
+    pub mass: f32,
+    pub cells: usize,
+}
+
+/// The life of one blob.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track {
+    pub id: u32,
+    /// Step of the first observation.
+    pub born: usize,
+    /// Step of the first observation it was missing from, if it ended.
+    pub died: Option<usize>,
+    /// Blobs it split off or merged out of; empty for a blob that appeared
+    /// from nothing (or was there from the first frame).
+    pub parents: Vec<u32>,
+    /// The blob it merged into, if it ended in a merge.
+    pub merged_into: Option<u32>,
+    pub points: Vec<TrackPoint>,
+}
+
+impl Track {
+    /// Steps from birth to death, or to the last observation if alive.
+    pub fn lifetime(&self) -> usize {
+        let end = self.died.unwrap_or_else(|| self.points.last().map_or(self.born, |p| p.step));
+        end - self.born
+    }
+
+    /// Net displacement from the first observation to the last.
+    pub fn displacement(&self) -> (f32, f32) {
+        match (self.points.first(), self.points.last()) {
+            (Some(a), Some(b)) => (b.x - a.x, b.y - a.y),
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Length of the path walked between observations.
+    pub fn path_length(&self) -> f32 {
+        self.points
+            .windows(2)
+            .map(|p| ((p[1].x - p[0].x).powi(2) + (p[1].y - p[0].y).powi(2)).sqrt())
+            .sum()
+    }
+}
+
+/// A structural event between two observed frames.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlobEvent {
+    /// A blob appeared overlapping nothing of the previous frame.
+    Birth { step: usize, id: u32 },
+    /// A blob vanished overlapping nothing of the current frame.
+    Death { step: usize, id: u32 },
+    /// `parent` now overlaps several blobs; `children` includes the one that
+    /// kept its identity, if any.
+    Split { step: usize, parent: u32, children: Vec<u32> },
+    /// Several previous blobs overlap `child`, which may be one of them.
+    Merge { step: usize, parents: Vec<u32>, child: u32 },
+}
+
+/// Assigns persistent IDs to blobs across observed frames (see the module
+/// docs). Feed it every frame with [`observe`](Self::observe).
+pub struct BlobTracker {
+    w: usize,
+    h: usize,
+    cfg: TrackConfig,
+    /// Previous frame: its blobs, their track indices and the cell labels.
+    prev: Vec<(Blob, usize)>,
+    prev_labels: Vec<Option<usize>>,
+    tracks: Vec<Track>,
+    events: Vec<BlobEvent>,
+    primed: bool,
+}
+
+impl BlobTracker {
+    pub fn new(w: usize, h: usize, cfg: TrackConfig) -> Self {
+        BlobTracker {
+            w,
+            h,
+            cfg,
+            prev: Vec::new(),
+            prev_labels: Vec::new(),
+            tracks: Vec::new(),
+            events: Vec::new(),
+            primed: false,
+        }
+    }
+
+    /// Label `field` at `threshold` and observe its blobs at `step`.
+    pub fn observe(&mut self, step: usize, field: &[f32], threshold: f32) {
+        let (comps, labels) = label_components(field, self.w, self.h, threshold);
+        self.observe_labeled(step, &comps, labels);
+    }
+
+    /// Observe blobs already labeled by
+    /// [`label_components`](crate::harness::label_components).
+    pub fn observe_labeled(&mut self, step: usize, comps: &Components, labels: Vec<Option<usize>>) {
+        // Drop blobs under the mass floor, relabeling the rest.
+        let mut keep = vec![None; comps.blobs.len()];
+        let mut blobs = Vec::with_capacity(comps.blobs.len());
+        for (i, blob) in comps.blobs.iter().enumerate() {
+            if blob.mass >= self.cfg.min_mass {
+                keep[i] = Some(blobs.len());
+                blobs.push(*blob);
+            }
+        }
+        let labels: Vec<Option<usize>> =
+            labels.into_iter().map(|l| l.and_then(|l| keep[l])).collect();
+        let (np, nc) = (self.prev.len(), blobs.len());
+
+        // Cell overlap between every previous and current blob.
+        let mut overlap = vec![0usize; np * nc];
+        if np > 0 {
+            for (p, c) in self.prev_labels.iter().zip(&labels) {
+                if let (Some(p), Some(c)) = (p, c) {
+                    overlap[p * nc + c] += 1;
+                }
+            }
+        }
+
+        // Optimal assignment on similarity; dissimilar pairs stay unmatched.
+        let similarity = |p: usize, c: usize| {
+            self.similarity(&self.prev[p].0, &blobs[c], overlap[p * nc + c])
+        };
+        let cost: Vec<Vec<f64>> =
+            (0..np).map(|p| (0..nc).map(|c| 1.0 - similarity(p, c)).collect()).collect();
+        let matched = assign(&cost);
+        let mut track_of: Vec<Option<usize>> = vec![None; nc];
+        for (p, m) in matched.iter().enumerate() {
+            if let Some(c) = *m {
+                track_of[c] = Some(self.prev[p].1);
+            }
+        }
+
+        // Everything left unmatched is born, dies, splits off or merges away.
+        let overlapping_prev = |c: usize| -> Vec<usize> {
+            (0..np).filter(|&p| overlap[p * nc + c] > 0).collect()
+        };
+        for (c, slot) in track_of.iter_mut().enumerate() {
+            if slot.is_some() {
+                continue;
+            }
+            let parents: Vec<u32> =
+                overlapping_prev(c).iter().map(|&p| self.tracks[self.prev[p].1].id).collect();
+            let id = self.tracks.len() as u32;
+            if self.primed && parents.is_empty() {
+                self.events.push(BlobEvent::Birth { step, id });
+            }
+            self.tracks.push(Track {
+                id,
+                born: step,
+                died: None,
+                parents,
+                merged_into: None,
+                points: Vec::new(),
+            });
+            *slot = Some(self.tracks.len() - 1);
+        }
+        for (p, m) in matched.iter().enumerate() {
+            if m.is_some() {
+                continue;
+            }
+            let t = self.prev[p].1;
+            self.tracks[t].died = Some(step);
+            let into = (0..nc)
+                .filter(|&c| overlap[p * nc + c] > 0)
+                .max_by_key(|&c| overlap[p * nc + c]);
+            match into {
+                Some(c) => self.tracks[t].merged_into = track_of[c].map(|i| self.tracks[i].id),
+                None => self.events.push(BlobEvent::Death { step, id: self.tracks[t].id }),
+            }
+        }
+        for p in 0..np {
+            let children: Vec<u32> = (0..nc)
+                .filter(|&c| overlap[p * nc + c] > 0)
+                .filter_map(|c| track_of[c].map(|t| self.tracks[t].id))
+                .collect();
+            if children.len() >= 2 {
+                let parent = self.tracks[self.prev[p].1].id;
+                self.events.push(BlobEvent::Split { step, parent, children });
+            }
+        }
+        for (c, t) in track_of.iter().enumerate() {
+            let parents = overlapping_prev(c);
+            if parents.len() >= 2 {
+                let parents = parents.iter().map(|&p| self.tracks[self.prev[p].1].id).collect();
+                let child = self.tracks[t.expect("every blob has a track")].id;
+                self.events.push(BlobEvent::Merge { step, parents, child });
+            }
+        }
+
+        // Extend every current track, unwrapping its centroid.
+        let mut prev = Vec::with_capacity(nc);
+        for (c, blob) in blobs.iter().enumerate() {
+            let t = track_of[c].expect("every blob has a track");
+            let (x, y) = match self.tracks[t].points.last() {
+                Some(last) => (
+                    last.x + wrap_delta(blob.cx - last.x, self.w as f32),
+                    last.y + wrap_delta(blob.cy - last.y, self.h as f32),
+                ),
+                None => (blob.cx, blob.cy),
+            };
+            let point = TrackPoint { step, x, y, mass: blob.mass, cells: blob.cells };
+            self.tracks[t].points.push(point);
+            prev.push((*blob, t));
+        }
+        self.prev = prev;
+        self.prev_labels = labels;
+        self.primed = true;
+    }
+
+    /// Every track ever started, in ID order (a track's ID is its index).
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Every event so far, in the order observed.
+    pub fn events(&self) -> &[BlobEvent] {
+        &self.events
+    }
+
+    /// Event counts in the vocabulary of the discrete
+    /// [`NarrativeTracker`](crate::narrative::NarrativeTracker).
+    pub fn narrative(&self) -> NarrativeStats {
+        let mut stats = NarrativeStats::default();
+        for event in &self.events {
+            match event {
+                BlobEvent::Birth { .. } => stats.births += 1,
+                BlobEvent::Death { .. } => stats.deaths += 1,
+                BlobEvent::Split { .. } => stats.splits += 1,
+                BlobEvent::Merge { .. } => stats.merges += 1,
+            }
+        }
+        stats.total_events = self.events.len();
+        stats
+    }
+
+    /// IDs of the blobs of the last observed frame at least `min_mass` heavy,
+    /// in its blob order.
+    pub fn current_ids(&self) -> Vec<u32> {
+        self.prev.iter().map(|&(_, t)| self.tracks[t].id).collect()
+    }
+
+    /// Every track descended from `id` through splits and merges, `id` not
+    /// included, in ID order.
+    pub fn descendants(&self, id: u32) -> Vec<u32> {
+        let mut family = vec![id];
+        let mut out = Vec::new();
+        while let Some(ancestor) = family.pop() {
+            let merged_into = self.tracks[ancestor as usize].merged_into;
+            let children = self
+                .tracks
+                .iter()
+                .filter(|t| t.parents.contains(&ancestor) || merged_into == Some(t.id));
+            for t in children {
+                if t.id != id && !out.contains(&t.id) {
+                    out.push(t.id);
+                    family.push(t.id);
+                }
+            }
+        }
+        out.sort_unstable();
+        out
+    }
+
+    /// A plain-text table of every track, one row per blob.
+    pub fn table(&self) -> String {
+        let mut out = String::from(
+            "id  born  died  life  parents  merged  x0  y0  dx  dy  path  mean_mass  peak_mass\n",
+        );
+        let ids = |ids: &[u32]| {
+            if ids.is_empty() {
+                "-".to_string()
+            } else {
+                ids.iter().map(u32::to_string).collect::<Vec<_>>().join(",")
+            }
+        };
+        for t in &self.tracks {
+            let first = t.points.first().copied().unwrap_or(TrackPoint {
+                step: t.born,
+                x: 0.0,
+                y: 0.0,
+                mass: 0.0,
+                cells: 0,
+            });
+            let (dx, dy) = t.displacement();
+            let masses = t.points.iter().map(|p| p.mass);
+            let mean = masses.clone().sum::<f32>() / t.points.len().max(1) as f32;
+            let peak = masses.fold(0.0f32, f32::max);
+            writeln!(
+                out,
+                "{}  {}  {}  {}  {}  {}  {:.1}  {:.1}  {:.1}  {:.1}  {:.1}  {:.2}  {:.2}",
+                t.id,
+                t.born,
+                t.died.map_or("-".to_string(), |d| d.to_string()),
+                t.lifetime(),
+                ids(&t.parents),
+                t.merged_into.map_or("-".to_string(), |m| m.to_string()),
+                first.x,
+                first.y,
+                dx,
+                dy,
+                t.path_length(),
+                mean,
+                peak
+            )
+            .expect("writing to a String");
+        }
+        out
+    }
+
+    /// Similarity of a previous and a current blob in `[0, 1]`; 0 when they
+    /// neither overlap nor lie within the match gate.
+    fn similarity(&self, a: &Blob, b: &Blob, overlap: usize) -> f64 {
+        let dx = wrap_delta(a.cx - b.cx, self.w as f32);
+        let dy = wrap_delta(a.cy - b.cy, self.h as f32);
+        let dist = (dx * dx + dy * dy).sqrt();
+        if overlap == 0 && dist > self.cfg.max_match_dist {
+            return 0.0;
+        }
+        let iou = overlap as f32 / (a.cells + b.cells - overlap).max(1) as f32;
+        let mass = a.mass.min(b.mass) / a.mass.max(b.mass).max(f32::MIN_POSITIVE);
+        let near = (1.0 - dist / self.cfg.max_match_dist).max(0.0);
+        let c = &self.cfg;
+        let total = c.overlap_weight + c.mass_weight + c.distance_weight;
+        let s = (c.overlap_weight * iou + c.mass_weight * mass + c.distance_weight * near) / total;
+        // Keep any admissible pair strictly better than leaving both unmatched.
+        s.max(1e-6) as f64
+    }
+}
+
+/// Minimum-cost assignment of rows to columns (Hungarian method, `O(n³)`).
+/// Costs are in `[0, 1]`; a pair costing 1 or more is no better than leaving
+/// both unmatched and is dropped. Returns the column matched to each row.
+fn assign(cost: &[Vec<f64>]) -> Vec<Option<usize>> {
+    let rows = cost.len();
+    let cols = cost.first().map_or(0, Vec::len);
+    let n = rows.max(cols);
+    if n == 0 {
+        return vec![None; rows];
+    }
+    // Pad to square; padding costs the same as an inadmissible pair.
+    let at = |i: usize, j: usize| {
+        if i < rows && j < cols {
+            cost[i][j].min(1.0)
+        } else {
+            1.0
+        }
+    };
+    // Potentials `u`, `v`; `p[j]` is the row (1-based) holding column `j`.
+    let (mut u, mut v) = (vec![0.0f64; n + 1], vec![0.0f64; n + 1]);
+    let (mut p, mut way) = (vec![0usize; n + 1], vec![0usize; n + 1]);
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![f64::INFINITY; n + 1];
+        let mut used = vec![false; n + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let (mut delta, mut j1) = (f64::INFINITY, 0);
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let cur = at(i0 - 1, j - 1) - u[i0] - v[j];
+                if cur < minv[j] {
+                    minv[j] = cur;
+                    way[j] = j0;
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        loop {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+    let mut out = vec![None; rows];
+    for (j, &row) in p.iter().enumerate().skip(1) {
+        let (i, c) = (row - 1, j - 1);
+        if i < rows && c < cols && cost[i][c] < 1.0 {
+            out[i] = Some(c);
+        }
+    }
+    out
+}
+
+/// Signed shortest offset `d` on a ring of length `size`.
+fn wrap_delta(d: f32, size: f32) -> f32 {
+    (d + size * 0.5).rem_euclid(size) - size * 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A field of solid disks `(cx, cy, radius)` on a `w × h` torus.
+    fn disks(w: usize, h: usize, disks: &[(f32, f32, f32)]) -> Vec<f32> {
+        let mut field = vec![0.0; w * h];
+        for &(cx, cy, r) in disks {
+            for y in 0..h {
+                for x in 0..w {
+                    let dx = wrap_delta(x as f32 - cx, w as f32);
+                    let dy = wrap_delta(y as f32 - cy, h as f32);
+                    if dx * dx + dy * dy <= r * r {
+                        field[y * w + x] = 1.0;
+                    }
+                }
+            }
+        }
+        field
+    }
+
+    #[test]
+    fn assignment_is_optimal_where_greedy_is_not() {
+        // Greedy takes (0, 0) first and is left with (1, 1): 1.0 in total.
+        let cost = vec![vec![0.1, 0.2], vec![0.15, 0.9]];
+        assert_eq!(assign(&cost), vec![Some(1), Some(0)]);
+        // Rectangular, and inadmissible pairs stay unmatched.
+        let cost = vec![vec![1.0, 0.3, 1.0]];
+        assert_eq!(assign(&cost), vec![Some(1)]);
+        assert_eq!(assign(&[vec![1.0], vec![1.0]]), vec![None, None]);
+    }
+
+    #[test]
+    fn moving_blobs_keep_their_ids_across_the_seam() {
+        let (w, h) = (32, 32);
+        let mut tracker = BlobTracker::new(w, h, TrackConfig::default());
+        for step in 0..12 {
+            let x = (20 + 2 * step) as f32 % w as f32;
+            tracker.observe(step, &disks(w, h, &[(x, 8.0, 3.0), (10.0, 24.0, 2.0)]), 0.5);
+        }
+        assert!(tracker.events().is_empty(), "{:?}", tracker.events());
+        let tracks = tracker.tracks();
+        assert_eq!(tracks.len(), 2);
+        let mover = &tracks[0];
+        assert_eq!(mover.points.len(), 12);
+        assert_eq!(mover.lifetime(), 11);
+        let (dx, dy) = mover.displacement();
+        assert!((dx - 22.0).abs() < 1e-3 && dy.abs() < 1e-3, "({dx}, {dy})");
+        assert!((mover.path_length() - 22.0).abs() < 1e-3);
+        assert_eq!(tracks[1].displacement(), (0.0, 0.0));
+        assert_eq!(tracker.current_ids(), vec![0, 1]);
+    }
+
+    #[test]
+    fn splits_and_merges_record_parents() {
+        let (w, h) = (32, 32);
+        let mut tracker = BlobTracker::new(w, h, TrackConfig::default());
+        tracker.observe(0, &disks(w, h, &[(16.0, 16.0, 5.0)]), 0.5);
+        // The blob pinches into two halves, one heavier.
+        tracker.observe(1, &disks(w, h, &[(12.0, 16.0, 3.5), (20.0, 16.0, 3.0)]), 0.5);
+        let daughter = 1;
+        assert_eq!(tracker.current_ids(), vec![0, daughter]);
+        assert_eq!(tracker.tracks()[1].parents, vec![0]);
+        assert_eq!(
+            tracker.events(),
+            &[BlobEvent::Split { step: 1, parent: 0, children: vec![0, daughter] }]
+        );
+        // They fuse again; the heavier keeps its identity.
+        tracker.observe(2, &disks(w, h, &[(15.0, 16.0, 5.0)]), 0.5);
+        assert_eq!(tracker.current_ids(), vec![0]);
+        let t = &tracker.tracks()[1];
+        assert_eq!((t.died, t.merged_into), (Some(2), Some(0)));
+        assert_eq!(
+            tracker.events()[1],
+            BlobEvent::Merge { step: 2, parents: vec![0, daughter], child: 0 }
+        );
+        assert_eq!(tracker.descendants(0), vec![daughter]);
+        // Far away, a blob appears and the merged one vanishes.
+        tracker.observe(3, &disks(w, h, &[(4.0, 4.0, 2.0)]), 0.5);
+        assert_eq!(
+            &tracker.events()[2..],
+            &[BlobEvent::Birth { step: 3, id: 2 }, BlobEvent::Death { step: 3, id: 0 }]
+        );
+        // A faint fleck is below the mass floor: no birth.
+        let mut field = disks(w, h, &[(4.0, 4.0, 2.0)]);
+        field[20 * w + 20] = 0.5;
+        tracker.observe(4, &field, 0.1);
+        let stats = tracker.narrative();
+        assert_eq!((stats.births, stats.deaths, stats.splits, stats.merges), (1, 1, 1, 1));
+        assert_eq!(stats.event_diversity(), 4);
+        let table = tracker.table();
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.lines().nth(2).unwrap().starts_with("1  1  2  1  0  0  "), "{table}");
+    }
+}